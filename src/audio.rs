@@ -0,0 +1,78 @@
+// ==================== AUDIO ====================
+// The simulation only emits SoundEvents; the windowed game turns them into
+// kira playback. Sound files are optional: a missing or broken file leaves
+// its cue silent (the console log still describes it), so runs without
+// assets, headless batches and CI keep working.
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::{Audio as KiraAudio, AudioControl, AudioSource as KiraAudioSource};
+use std::path::Path;
+
+use crate::{Faction, GameAssets, UnitType};
+
+const ASSET_DIR: &str = "assets";
+
+pub const MILITARY_GUNFIRE_PATH: &str = "sounds/military_gunfire.ogg";
+pub const CARTEL_GUNFIRE_PATH: &str = "sounds/cartel_gunfire.ogg";
+pub const DEATH_PATH: &str = "sounds/death.ogg";
+pub const EXPLOSION_PATH: &str = "sounds/explosion.ogg";
+pub const RADIO_CHATTER_PATH: &str = "sounds/radio_chatter.ogg";
+pub const CONSTRUCTION_PATH: &str = "sounds/roadblock_construction.ogg";
+
+#[derive(Event, Clone, Debug)]
+pub enum SoundEvent {
+    Gunfire { faction: Faction },
+    UnitKilled { unit_type: UnitType },
+    CivilianKilled,
+    WaveRadio,
+    RoadblockBuilt,
+}
+
+// Loads a sound through the asset server if the file is there
+pub fn load_sound(asset_server: &AssetServer, path: &str) -> Option<Handle<KiraAudioSource>> {
+    if Path::new(ASSET_DIR).join(path).exists() {
+        Some(asset_server.load(path.to_string()))
+    } else {
+        warn!("🔇 Sound '{}' not found, falling back to console cues", path);
+        None
+    }
+}
+
+pub fn play_sound_system(
+    mut events: EventReader<SoundEvent>,
+    audio: Res<KiraAudio>,
+    assets: Res<GameAssets>,
+    asset_server: Res<AssetServer>,
+) {
+    // Dozens of shots can land in one frame; one gunfire cue per faction is enough
+    let mut cartel_fired = false;
+    let mut military_fired = false;
+
+    for event in events.read() {
+        let (sound, volume) = match event {
+            SoundEvent::Gunfire { faction: Faction::Cartel } if !cartel_fired => {
+                cartel_fired = true;
+                (&assets.cartel_gunfire_sound, 0.4)
+            }
+            SoundEvent::Gunfire { faction: Faction::Military } if !military_fired => {
+                military_fired = true;
+                (&assets.military_gunfire_sound, 0.4)
+            }
+            SoundEvent::Gunfire { .. } => continue,
+            SoundEvent::UnitKilled { unit_type: UnitType::Roadblock | UnitType::Vehicle | UnitType::NarcoTechTruck } => (&assets.explosion_sound, 0.8),
+            SoundEvent::UnitKilled { .. } | SoundEvent::CivilianKilled => (&assets.death_sound, 0.6),
+            SoundEvent::WaveRadio => (&assets.radio_chatter, 0.7),
+            SoundEvent::RoadblockBuilt => (&assets.construction_sound, 0.6),
+        };
+
+        let Some(handle) = sound else {
+            continue;
+        };
+        // Files that exist but fail to decode stay silent too
+        if matches!(asset_server.get_load_state(handle), Some(LoadState::Failed)) {
+            continue;
+        }
+        audio.play(handle.clone()).with_volume(volume);
+    }
+}
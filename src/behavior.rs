@@ -0,0 +1,366 @@
+// ==================== BEHAVIORS ====================
+// Utility AI shared by every faction. Each unit type (and civilians) has a
+// list of behaviors in assets/data/unit_behaviors.json; a behavior scores its
+// weight times each of its considerations, all between 0 and 1, and the best
+// score wins. The systems that move a unit carry out the winning action, so a
+// new unit type only needs an entry in the data file.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::data;
+use crate::squads::SquadMember;
+use crate::UnitType;
+
+pub const UNIT_BEHAVIORS_PATH: &str = "assets/data/unit_behaviors.json";
+
+const BUILTIN_UNIT_BEHAVIORS: &str = include_str!("../assets/data/unit_behaviors.json");
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Action {
+    // Stand still and do nothing new
+    #[default]
+    Hold,
+    // Keep going to the position the player ordered
+    FollowOrders,
+    // Fire on the nearest enemy in range, or keep firing at the current target
+    Engage,
+    // Close in on Ovidio, falling back on the safehouse
+    Advance,
+    // Ride along with Ovidio while he makes a run for it
+    EscortOvidio,
+    // Run from gunfire
+    Flee,
+    // Stroll around the neighbourhood
+    Wander,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Consideration {
+    HasMoveOrder,
+    NoMoveOrder,
+    HasTarget,
+    NoTarget,
+    // An enemy is in weapon range, or the unit is already firing at one
+    EnemyInRange,
+    OvidioFleeingNearby,
+    Panicked,
+    // Share of health left, and its complement
+    Health,
+    Wounded,
+    // Whether the unit's side is run by the AI or by the player
+    AiControlled,
+    PlayerControlled,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Behavior {
+    pub action: Action,
+    pub weight: f32,
+    #[serde(default)]
+    pub considerations: Vec<Consideration>,
+}
+
+// What a unit knows about itself and its surroundings this tick
+#[derive(Clone, Copy, Debug)]
+pub struct Situation {
+    pub has_move_order: bool,
+    pub has_target: bool,
+    pub enemy_in_range: bool,
+    pub ovidio_fleeing_nearby: bool,
+    pub panicked: bool,
+    pub health: f32,
+    pub ai_controlled: bool,
+}
+
+impl Default for Situation {
+    fn default() -> Self {
+        Self {
+            has_move_order: false,
+            has_target: false,
+            enemy_in_range: false,
+            ovidio_fleeing_nearby: false,
+            panicked: false,
+            health: 1.0,
+            ai_controlled: true,
+        }
+    }
+}
+
+fn flag(value: bool) -> f32 {
+    if value { 1.0 } else { 0.0 }
+}
+
+impl Consideration {
+    pub fn score(&self, situation: &Situation) -> f32 {
+        match self {
+            Consideration::HasMoveOrder => flag(situation.has_move_order),
+            Consideration::NoMoveOrder => flag(!situation.has_move_order),
+            Consideration::HasTarget => flag(situation.has_target),
+            Consideration::NoTarget => flag(!situation.has_target),
+            Consideration::EnemyInRange => flag(situation.enemy_in_range || situation.has_target),
+            Consideration::OvidioFleeingNearby => flag(situation.ovidio_fleeing_nearby),
+            Consideration::Panicked => flag(situation.panicked),
+            Consideration::Health => situation.health.clamp(0.0, 1.0),
+            Consideration::Wounded => 1.0 - situation.health.clamp(0.0, 1.0),
+            Consideration::AiControlled => flag(situation.ai_controlled),
+            Consideration::PlayerControlled => flag(!situation.ai_controlled),
+        }
+    }
+}
+
+impl Behavior {
+    pub fn score(&self, situation: &Situation) -> f32 {
+        self.considerations.iter()
+            .map(|consideration| consideration.score(situation))
+            .fold(self.weight, |score, factor| score * factor)
+    }
+}
+
+// The action a unit settled on and how strongly, kept for the debug overlay
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct Decision {
+    pub action: Action,
+    pub score: f32,
+}
+
+// Highest-scoring behavior; ties go to the one listed first, and nothing
+// scoring zero is ever picked
+pub fn choose(behaviors: &[Behavior], situation: &Situation) -> Option<Decision> {
+    behaviors.iter()
+        .map(|behavior| Decision {
+            action: behavior.action,
+            score: behavior.score(situation),
+        })
+        .filter(|decision| decision.score > 0.0)
+        .fold(None, |best: Option<Decision>, decision| match best {
+            Some(best) if best.score >= decision.score => Some(best),
+            _ => Some(decision),
+        })
+}
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct BehaviorLibrary {
+    units: HashMap<UnitType, Vec<Behavior>>,
+    civilian: Vec<Behavior>,
+}
+
+impl BehaviorLibrary {
+    // An empty list means the unit never acts on its own
+    pub fn for_unit(&self, unit_type: &UnitType) -> &[Behavior] {
+        // Every variant is checked when the library is built
+        &self.units[unit_type]
+    }
+
+    pub fn civilian(&self) -> &[Behavior] {
+        &self.civilian
+    }
+}
+
+// ==================== LOADING ====================
+
+impl BehaviorLibrary {
+    fn problems(&self) -> Vec<String> {
+        let missing: Vec<&str> = UnitType::NAMES
+            .iter()
+            .filter(|name| {
+                let unit_type = name.parse::<UnitType>().expect("UnitType::NAMES must parse");
+                !self.units.contains_key(&unit_type)
+            })
+            .copied()
+            .collect();
+        if missing.is_empty() {
+            Vec::new()
+        } else {
+            vec![format!("no entry for: {}", missing.join(", "))]
+        }
+    }
+}
+
+pub fn load_behavior_library_or_builtin(path: &str) -> BehaviorLibrary {
+    data::load_json_or_builtin(path, BUILTIN_UNIT_BEHAVIORS, BehaviorLibrary::problems)
+}
+
+// ==================== DEBUG OVERLAY ====================
+
+// F3 shows what every unit is currently doing and why
+#[derive(Resource, Default)]
+pub struct AiDebug {
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+pub struct DecisionLabel;
+
+pub fn decision_label_system(
+    keys: Res<Input<KeyCode>>,
+    mut ai_debug: ResMut<AiDebug>,
+    unit_query: Query<(&Decision, Option<&SquadMember>, &Children)>,
+    mut label_query: Query<(&mut Text, &mut Visibility), With<DecisionLabel>>,
+) {
+    if keys.just_pressed(KeyCode::F3) {
+        ai_debug.enabled = !ai_debug.enabled;
+        info!("🧠 AI debug overlay {}", if ai_debug.enabled { "on" } else { "off" });
+    }
+
+    for (decision, member, children) in unit_query.iter() {
+        for &child in children.iter() {
+            let Ok((mut text, mut visibility)) = label_query.get_mut(child) else {
+                continue;
+            };
+            *visibility = if ai_debug.enabled { Visibility::Visible } else { Visibility::Hidden };
+            if ai_debug.enabled {
+                // Fireteam soldiers answer to the squad AI, not to their own behaviors
+                text.sections[0].value = match member {
+                    Some(member) => format!("T{} {:?}", member.squad, member.role),
+                    None => format!("{:?} {:.2}", decision.action, decision.score),
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataError;
+
+    fn behavior(action: Action, weight: f32, considerations: &[Consideration]) -> Behavior {
+        Behavior {
+            action,
+            weight,
+            considerations: considerations.to_vec(),
+        }
+    }
+
+    fn builtin() -> BehaviorLibrary {
+        data::parse_json("<built-in>", BUILTIN_UNIT_BEHAVIORS, BehaviorLibrary::problems).expect("built-in unit behaviors must be valid")
+    }
+
+    #[test]
+    fn score_multiplies_weight_by_considerations() {
+        let situation = Situation {
+            health: 0.25,
+            enemy_in_range: true,
+            ..default()
+        };
+        let wounded = behavior(Action::Hold, 0.8, &[Consideration::EnemyInRange, Consideration::Wounded]);
+        assert!((wounded.score(&situation) - 0.6).abs() < 1e-6);
+        assert_eq!(behavior(Action::Hold, 0.8, &[]).score(&situation), 0.8);
+    }
+
+    #[test]
+    fn choose_picks_the_best_score_and_the_first_on_ties() {
+        let behaviors = [
+            behavior(Action::Advance, 0.5, &[]),
+            behavior(Action::Hold, 0.5, &[]),
+            behavior(Action::Engage, 1.0, &[Consideration::EnemyInRange]),
+        ];
+        let calm = choose(&behaviors, &Situation::default()).unwrap();
+        assert_eq!(calm.action, Action::Advance);
+
+        let contact = Situation {
+            enemy_in_range: true,
+            ..default()
+        };
+        assert_eq!(choose(&behaviors, &contact).unwrap().action, Action::Engage);
+    }
+
+    #[test]
+    fn choose_never_picks_a_zero_score() {
+        let behaviors = [behavior(Action::Flee, 1.0, &[Consideration::Panicked])];
+        assert!(choose(&behaviors, &Situation::default()).is_none());
+        assert!(choose(&[], &Situation::default()).is_none());
+    }
+
+    #[test]
+    fn builtin_library_covers_every_unit_type() {
+        let library = builtin();
+        for name in UnitType::NAMES {
+            let unit_type = name.parse::<UnitType>().unwrap();
+            let _ = library.for_unit(&unit_type);
+        }
+        assert!(library.for_unit(&UnitType::Roadblock).is_empty());
+    }
+
+    #[test]
+    fn missing_unit_type_is_reported() {
+        let result = data::parse_json("test", r#"{ "units": { "Sicario": [] }, "civilian": [] }"#, BehaviorLibrary::problems);
+        match result {
+            Err(DataError::Invalid { problems, .. }) => assert!(problems[0].contains("Soldier")),
+            other => panic!("expected a missing entry error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn sicario_move_orders_beat_engaging() {
+        let library = builtin();
+        let ordered = Situation {
+            has_move_order: true,
+            enemy_in_range: true,
+            ..default()
+        };
+        let decision = choose(library.for_unit(&UnitType::Sicario), &ordered).unwrap();
+        assert_eq!(decision.action, Action::FollowOrders);
+
+        let idle = Situation {
+            enemy_in_range: true,
+            ..default()
+        };
+        assert_eq!(choose(library.for_unit(&UnitType::Sicario), &idle).unwrap().action, Action::Engage);
+    }
+
+    #[test]
+    fn sicario_escorts_a_fleeing_ovidio_over_orders() {
+        let situation = Situation {
+            has_move_order: true,
+            ovidio_fleeing_nearby: true,
+            ..default()
+        };
+        let decision = choose(builtin().for_unit(&UnitType::Sicario), &situation).unwrap();
+        assert_eq!(decision.action, Action::EscortOvidio);
+    }
+
+    #[test]
+    fn soldiers_advance_until_they_find_an_enemy() {
+        let library = builtin();
+        let decision = choose(library.for_unit(&UnitType::Soldier), &Situation::default()).unwrap();
+        assert_eq!(decision.action, Action::Advance);
+
+        let engaged = Situation {
+            has_target: true,
+            ..default()
+        };
+        assert_eq!(choose(library.for_unit(&UnitType::Soldier), &engaged).unwrap().action, Action::Engage);
+    }
+
+    #[test]
+    fn player_soldiers_follow_orders_instead_of_advancing() {
+        let library = builtin();
+        let idle = Situation {
+            ai_controlled: false,
+            ..default()
+        };
+        assert_eq!(choose(library.for_unit(&UnitType::Soldier), &idle).unwrap().action, Action::Hold);
+
+        let ordered = Situation {
+            has_move_order: true,
+            enemy_in_range: true,
+            ai_controlled: false,
+            ..default()
+        };
+        assert_eq!(choose(library.for_unit(&UnitType::Soldier), &ordered).unwrap().action, Action::FollowOrders);
+    }
+
+    #[test]
+    fn civilians_flee_when_panicked() {
+        let library = builtin();
+        assert_eq!(choose(library.civilian(), &Situation::default()).unwrap().action, Action::Wander);
+        let panicked = Situation {
+            panicked: true,
+            ..default()
+        };
+        assert_eq!(choose(library.civilian(), &panicked).unwrap().action, Action::Flee);
+    }
+}
@@ -0,0 +1,299 @@
+// ==================== OVIDIO CAPTURE & EXTRACTION ====================
+// Soldiers who reach an unprotected Ovidio take him into custody and escort
+// him to the extraction point over extraction_time; the cartel can free him
+// by driving the escort off. The cartel can also try to move him out of the
+// city along one of the escape routes from the Ovidio metadata.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::map::MapLayout;
+use crate::ovidio::{EscapeRoute, OvidioMetadata};
+use crate::{Faction, GameState, Movement, Objective, ObjectiveType, Unit, UnitType, WorldPosition};
+
+// Map zone where the military hands Ovidio over
+pub const EXTRACTION_LANDMARK: &str = "military_base";
+
+// Guards this close to Ovidio count toward his protection detail
+const PROTECTION_RADIUS: f32 = 60.0;
+// Soldiers this close to Ovidio in custody keep the escort going
+const ESCORT_RADIUS: f32 = 80.0;
+// Ovidio has reached the end of an escape route within this distance
+const ESCAPE_ARRIVAL_RADIUS: f32 = 30.0;
+// Seconds he must hold at the route's end, scaled by its difficulty
+const ESCAPE_HOLD_TIME: f32 = 60.0;
+
+#[derive(Clone, Default, PartialEq, Debug, Serialize, Deserialize)]
+pub enum OvidioStatus {
+    #[default]
+    Free,
+    // Detained and escorted toward the extraction point
+    InCustody { elapsed: f32 },
+    // Being moved out of the city; `held` counts time at the route's end
+    Escaping { route_id: String, held: f32 },
+    Extracted,
+    Escaped { route_id: String },
+}
+
+impl OvidioStatus {
+    pub fn in_custody(&self) -> bool {
+        matches!(self, OvidioStatus::InCustody { .. })
+    }
+
+    // Short HUD description
+    pub fn label(&self, metadata: &OvidioMetadata) -> String {
+        match self {
+            OvidioStatus::Free => "SAFE".to_string(),
+            OvidioStatus::InCustody { elapsed } => format!(
+                "IN CUSTODY ({:.0}% extracted)",
+                elapsed / metadata.capture_mechanics.extraction_time * 100.0
+            ),
+            OvidioStatus::Escaping { route_id, .. } => format!("ESCAPING via {}", route_id),
+            OvidioStatus::Extracted => "EXTRACTED".to_string(),
+            OvidioStatus::Escaped { route_id } => format!("ESCAPED via {}", route_id),
+        }
+    }
+}
+
+fn find_route<'a>(metadata: &'a OvidioMetadata, route_id: &str) -> Option<&'a EscapeRoute> {
+    metadata.capture_mechanics.escape_routes.iter().find(|route| route.route_id == route_id)
+}
+
+// Sends Ovidio down the `index`th escape route, if he is free to go
+pub fn start_escape(game_state: &mut GameState, metadata: &OvidioMetadata, layout: &MapLayout, index: usize) {
+    let Some(route) = metadata.capture_mechanics.escape_routes.get(index) else {
+        return;
+    };
+    match game_state.ovidio_status {
+        OvidioStatus::Free | OvidioStatus::Escaping { .. } => {}
+        _ => {
+            warn!("🚫 Ovidio cannot be moved right now");
+            return;
+        }
+    }
+    if layout.landmark(route.destination_id()).is_none() {
+        warn!("🗺️ Escape route '{}' leads to unknown map location '{}'", route.route_id, route.destination_id());
+        return;
+    }
+
+    info!("🚙 Moving Ovidio out via {} (difficulty {:.0}%) 📻 '¡Sácalo de aquí!'", route.route_id, route.difficulty * 100.0);
+    game_state.ovidio_status = OvidioStatus::Escaping {
+        route_id: route.route_id.clone(),
+        held: 0.0,
+    };
+}
+
+pub fn capture_system(
+    time: Res<Time>,
+    metadata: Res<OvidioMetadata>,
+    layout: Res<MapLayout>,
+    mut game_state: ResMut<GameState>,
+    unit_query: Query<(Entity, &Unit, &WorldPosition)>,
+    mut movement_query: Query<&mut Movement>,
+    objective_query: Query<&Objective>,
+) {
+    let Some((ovidio, ovidio_unit, ovidio_position)) = unit_query.iter()
+        .find(|(_, unit, _)| unit.unit_type == UnitType::Ovidio && unit.health > 0.0)
+        .map(|(entity, unit, position)| (entity, unit, position.0))
+    else {
+        return;
+    };
+    let Ok(mut movement) = movement_query.get_mut(ovidio) else {
+        return;
+    };
+    let mechanics = &metadata.capture_mechanics;
+
+    let guards = unit_query.iter()
+        .filter(|(_, unit, position)| {
+            unit.faction == Faction::Cartel
+                && !matches!(unit.unit_type, UnitType::Roadblock | UnitType::Ovidio)
+                && position.0.distance(ovidio_position) <= PROTECTION_RADIUS
+        })
+        .count() as u32;
+    let soldier_distances: Vec<f32> = unit_query.iter()
+        .filter(|(_, unit, _)| unit.faction == Faction::Military)
+        .map(|(_, _, position)| position.0.distance(ovidio_position))
+        .collect();
+    let escort = soldier_distances.iter().filter(|d| **d <= ESCORT_RADIUS).count();
+
+    // A thin protection detail lets soldiers within reach take him
+    let free = matches!(game_state.ovidio_status, OvidioStatus::Free | OvidioStatus::Escaping { .. });
+    if free && guards < mechanics.protection_detail && soldier_distances.iter().any(|d| *d <= mechanics.capture_range) {
+        info!("🚨 *RADIO* 📻 'We have him!' Ovidio Guzmán López taken into custody - escorting to extraction");
+        game_state.ovidio_status = OvidioStatus::InCustody { elapsed: 0.0 };
+        movement.target_position = None;
+    }
+
+    let dt = time.delta_seconds();
+    match &mut game_state.ovidio_status {
+        OvidioStatus::Free => {
+            movement.speed = ovidio_unit.movement_speed;
+        }
+        OvidioStatus::InCustody { elapsed } => {
+            if escort == 0 {
+                movement.target_position = None;
+                if guards > 0 {
+                    info!("🔓 Cartel gunmen overrun the escort - Ovidio is free!");
+                    movement.speed = ovidio_unit.movement_speed;
+                    game_state.ovidio_status = OvidioStatus::Free;
+                }
+                return;
+            }
+
+            *elapsed += dt;
+            if *elapsed >= mechanics.extraction_time {
+                info!("🚁 Ovidio has been extracted from Culiacán!");
+                movement.target_position = None;
+                game_state.ovidio_status = OvidioStatus::Extracted;
+                return;
+            }
+
+            // The escort sets the pace: arrive as the extraction time runs out
+            let extraction = objective_query.iter()
+                .find(|objective| objective.objective_type == ObjectiveType::ExtractionPoint)
+                .map(|objective| objective.position);
+            if let Some(extraction) = extraction {
+                let remaining = (mechanics.extraction_time - *elapsed).max(1.0);
+                movement.target_position = Some(extraction);
+                movement.speed = (ovidio_position.distance(extraction) / remaining).min(ovidio_unit.movement_speed);
+            }
+        }
+        OvidioStatus::Escaping { route_id, held } => {
+            let route = find_route(&metadata, route_id);
+            let Some((route, destination)) = route.and_then(|route| Some((route, layout.landmark(route.destination_id())?))) else {
+                game_state.ovidio_status = OvidioStatus::Free;
+                return;
+            };
+
+            movement.speed = ovidio_unit.movement_speed;
+            if ovidio_position.distance(destination) > ESCAPE_ARRIVAL_RADIUS {
+                movement.target_position = Some(destination);
+                return;
+            }
+
+            *held += dt;
+            if *held >= ESCAPE_HOLD_TIME * route.difficulty {
+                info!("🛣️ Ovidio slips out of Culiacán via {}!", route.route_id);
+                game_state.ovidio_status = OvidioStatus::Escaped { route_id: route.route_id.clone() };
+            }
+        }
+        OvidioStatus::Extracted | OvidioStatus::Escaped { .. } => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    use crate::map::{load_map_or_builtin, MAP_LAYOUT_PATH};
+    use crate::ovidio::{load_ovidio_metadata_or_builtin, OVIDIO_METADATA_PATH};
+
+    const EXTRACTION: Vec3 = Vec3::new(300.0, 0.0, 0.0);
+
+    fn unit(faction: Faction, unit_type: UnitType) -> Unit {
+        Unit {
+            health: 100.0,
+            max_health: 100.0,
+            faction,
+            unit_type,
+            damage: 0.0,
+            range: 0.0,
+            movement_speed: 50.0,
+            target: None,
+            attack_cooldown: Timer::default(),
+        }
+    }
+
+    fn spawn(world: &mut World, faction: Faction, unit_type: UnitType, position: Vec3) -> Entity {
+        world.spawn((
+            unit(faction, unit_type),
+            WorldPosition(position),
+            Movement { target_position: None, speed: 50.0 },
+        )).id()
+    }
+
+    // Ovidio alone at the origin, with the extraction point off to the east
+    fn world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(load_ovidio_metadata_or_builtin(OVIDIO_METADATA_PATH));
+        world.insert_resource(load_map_or_builtin(MAP_LAYOUT_PATH));
+        world.insert_resource(GameState::default());
+        spawn(&mut world, Faction::Cartel, UnitType::Ovidio, Vec3::ZERO);
+        world.spawn(Objective {
+            objective_type: ObjectiveType::ExtractionPoint,
+            position: EXTRACTION,
+            radius: 50.0,
+            health: 100.0,
+        });
+        world
+    }
+
+    fn tick(world: &mut World, seconds: f32) {
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+        world.run_system_once(capture_system);
+    }
+
+    fn status(world: &World) -> OvidioStatus {
+        world.resource::<GameState>().ovidio_status.clone()
+    }
+
+    fn extraction_time(world: &World) -> f32 {
+        world.resource::<OvidioMetadata>().capture_mechanics.extraction_time
+    }
+
+    #[test]
+    fn an_escorted_ovidio_is_extracted_once_the_time_runs_out() {
+        let mut world = world();
+        spawn(&mut world, Faction::Military, UnitType::Soldier, Vec3::new(10.0, 0.0, 0.0));
+
+        // The tick he is taken already counts toward the extraction
+        tick(&mut world, 1.0);
+        assert_eq!(status(&world), OvidioStatus::InCustody { elapsed: 1.0 });
+
+        let seconds = extraction_time(&world) as u32;
+        for _ in 2..seconds {
+            tick(&mut world, 1.0);
+        }
+        assert!(status(&world).in_custody(), "extracted before extraction_time ran out");
+        let ovidio = world.query::<(&Unit, &Movement)>().iter(&world)
+            .find(|(unit, _)| unit.unit_type == UnitType::Ovidio)
+            .and_then(|(_, movement)| movement.target_position);
+        assert_eq!(ovidio, Some(EXTRACTION));
+
+        tick(&mut world, 1.0);
+        assert_eq!(status(&world), OvidioStatus::Extracted);
+    }
+
+    #[test]
+    fn a_full_protection_detail_keeps_him_out_of_custody() {
+        let mut world = world();
+        let detail = world.resource::<OvidioMetadata>().capture_mechanics.protection_detail;
+        for i in 0..detail {
+            spawn(&mut world, Faction::Cartel, UnitType::Sicario, Vec3::new(-20.0, i as f32 * 10.0, 0.0));
+        }
+        spawn(&mut world, Faction::Military, UnitType::Soldier, Vec3::new(10.0, 0.0, 0.0));
+
+        tick(&mut world, 1.0);
+        assert_eq!(status(&world), OvidioStatus::Free);
+    }
+
+    #[test]
+    fn guards_free_him_once_the_escort_is_gone() {
+        let mut world = world();
+        let soldier = spawn(&mut world, Faction::Military, UnitType::Soldier, Vec3::new(10.0, 0.0, 0.0));
+        tick(&mut world, 1.0);
+        assert!(status(&world).in_custody());
+
+        // Without an escort or guards the extraction stalls
+        world.despawn(soldier);
+        tick(&mut world, 10.0);
+        assert_eq!(status(&world), OvidioStatus::InCustody { elapsed: 1.0 });
+
+        spawn(&mut world, Faction::Cartel, UnitType::Sicario, Vec3::new(-20.0, 0.0, 0.0));
+        tick(&mut world, 1.0);
+        assert_eq!(status(&world), OvidioStatus::Free);
+    }
+}
@@ -0,0 +1,259 @@
+// ==================== CIVILIANS ====================
+// Residents populate each zone according to its civilian_density, wander
+// around their neighbourhood, flee from nearby gunfire and can be caught by
+// stray fire. Casualties are attributed to the faction that fired the shot.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::audio::SoundEvent;
+use crate::behavior::{self, Action, BehaviorLibrary, Decision, DecisionLabel, Situation};
+use crate::map::Zone;
+use crate::nav::NavGrid;
+use crate::sim::{ShotFired, SimRng};
+use crate::{world_to_iso, Faction, GameState, WorldPosition};
+
+// One resident per this many square map units at civilian_density 1.0
+const AREA_PER_CIVILIAN: f32 = 1000.0;
+const CIVILIAN_HEALTH: f32 = 30.0;
+const WALK_SPEED: f32 = 25.0;
+const FLEE_SPEED: f32 = 90.0;
+// Gunfire within this distance sends civilians running
+const PANIC_RADIUS: f32 = 180.0;
+const PANIC_DURATION: f32 = 6.0;
+// Civilians this close to a line of fire risk being hit
+const STRAY_FIRE_RADIUS: f32 = 15.0;
+const STRAY_HIT_CHANCE: f64 = 0.2;
+
+#[derive(Component, Clone, Debug)]
+pub struct Civilian {
+    pub health: f32,
+    // Neighbourhood the civilian wanders in when calm
+    pub home_min: Vec2,
+    pub home_max: Vec2,
+    pub destination: Option<Vec3>,
+    // Seconds of fleeing left, and the gunfire being fled from
+    pub panic: f32,
+    pub flee_from: Vec3,
+}
+
+#[derive(Clone, Copy, Default, Debug, Serialize, Deserialize)]
+pub struct CivilianCasualties {
+    pub by_cartel: u32,
+    pub by_military: u32,
+}
+
+impl CivilianCasualties {
+    pub fn record(&mut self, faction: &Faction) {
+        match faction {
+            Faction::Cartel => self.by_cartel += 1,
+            Faction::Military => self.by_military += 1,
+            Faction::Civilian => {}
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.by_cartel + self.by_military
+    }
+}
+
+impl Civilian {
+    pub fn new(home_min: Vec2, home_max: Vec2) -> Self {
+        Self {
+            health: CIVILIAN_HEALTH,
+            home_min,
+            home_max,
+            destination: None,
+            panic: 0.0,
+            flee_from: Vec3::ZERO,
+        }
+    }
+}
+
+pub fn spawn_civilian(commands: &mut Commands, civilian: Civilian, position: Vec3) -> Entity {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.7, 0.75, 0.9),
+                custom_size: Some(Vec2::new(6.0, 6.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(world_to_iso(position)),
+            ..default()
+        },
+        WorldPosition(position),
+        civilian,
+        Decision::default(),
+    )).with_children(|parent| {
+        // What the resident is up to, shown with F3 like the units
+        parent.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 10.0,
+                        color: Color::rgb(0.6, 0.9, 1.0),
+                        ..default()
+                    },
+                ),
+                transform: Transform::from_xyz(0.0, -10.0, 2.0),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DecisionLabel,
+        ));
+    }).id()
+}
+
+// Scatters residents over every zone in proportion to its area and density
+pub fn populate_zones(commands: &mut Commands, zones: &[Zone], grid: &NavGrid, rng: &mut SimRng) {
+    let mut total = 0;
+    for zone in zones {
+        let count = (zone.size.x * zone.size.y * zone.civilian_density / AREA_PER_CIVILIAN).round() as u32;
+        let home_min = zone.center.truncate() - zone.size * 0.5;
+        let home_max = zone.center.truncate() + zone.size * 0.5;
+
+        for _ in 0..count {
+            // A few tries to land on an open street rather than inside a building
+            let position = (0..8)
+                .map(|_| random_point(rng, home_min, home_max))
+                .find(|point| !grid.is_blocked(grid.world_to_cell(*point)));
+            if let Some(position) = position {
+                spawn_civilian(commands, Civilian::new(home_min, home_max), position);
+                total += 1;
+            }
+        }
+    }
+    info!("👥 {} civilians going about their day in Culiacán", total);
+}
+
+fn random_point(rng: &mut SimRng, min: Vec2, max: Vec2) -> Vec3 {
+    Vec3::new(rng.rng.gen_range(min.x..=max.x), rng.rng.gen_range(min.y..=max.y), 0.0)
+}
+
+// Distance from `point` to the segment `from`-`to`
+fn distance_to_segment(point: Vec3, from: Vec3, to: Vec3) -> f32 {
+    let segment = to - from;
+    let length_squared = segment.length_squared();
+    if length_squared == 0.0 {
+        return point.distance(from);
+    }
+    let t = ((point - from).dot(segment) / length_squared).clamp(0.0, 1.0);
+    point.distance(from + segment * t)
+}
+
+// ==================== SYSTEMS ====================
+
+pub fn stray_fire_system(
+    mut commands: Commands,
+    mut shots: EventReader<ShotFired>,
+    mut rng: ResMut<SimRng>,
+    mut game_state: ResMut<GameState>,
+    mut sounds: EventWriter<SoundEvent>,
+    mut civilian_query: Query<(Entity, &mut Civilian, &WorldPosition)>,
+) {
+    for shot in shots.read() {
+        for (entity, mut civilian, position) in civilian_query.iter_mut() {
+            if civilian.health <= 0.0 || distance_to_segment(position.0, shot.from, shot.to) > STRAY_FIRE_RADIUS {
+                continue;
+            }
+            if !rng.rng.gen_bool(STRAY_HIT_CHANCE) {
+                continue;
+            }
+
+            civilian.health -= shot.damage;
+            if civilian.health <= 0.0 {
+                game_state.civilian_casualties.record(&shot.faction);
+                info!("🕊️ Civilian killed by {:?} fire ({} civilian casualties)", shot.faction, game_state.civilian_casualties.total());
+                sounds.send(SoundEvent::CivilianKilled);
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+pub fn civilian_panic_system(
+    mut shots: EventReader<ShotFired>,
+    mut civilian_query: Query<(&mut Civilian, &WorldPosition)>,
+) {
+    for shot in shots.read() {
+        for (mut civilian, position) in civilian_query.iter_mut() {
+            if position.0.distance(shot.from).min(position.0.distance(shot.to)) <= PANIC_RADIUS {
+                civilian.panic = PANIC_DURATION;
+                civilian.flee_from = shot.from;
+                civilian.destination = None;
+            }
+        }
+    }
+}
+
+pub fn civilian_movement_system(
+    time: Res<Time>,
+    grid: Res<NavGrid>,
+    mut rng: ResMut<SimRng>,
+    library: Res<BehaviorLibrary>,
+    mut civilian_query: Query<(&mut Civilian, &mut WorldPosition, &mut Decision)>,
+) {
+    let delta = time.delta_seconds();
+
+    for (mut civilian, mut position, mut decision) in civilian_query.iter_mut() {
+        let situation = Situation {
+            panicked: civilian.panic > 0.0,
+            health: civilian.health / CIVILIAN_HEALTH,
+            ..default()
+        };
+        let Some(chosen) = behavior::choose(library.civilian(), &situation) else {
+            continue;
+        };
+        *decision = chosen;
+
+        let step = match chosen.action {
+            Action::Flee => {
+                civilian.panic -= delta;
+                let away = (position.0 - civilian.flee_from).truncate().normalize_or_zero();
+                away.extend(0.0) * FLEE_SPEED * delta
+            }
+            Action::Wander => {
+                // Calm civilians stroll between random spots in their neighbourhood
+                let destination = match civilian.destination {
+                    Some(destination) if position.0.distance(destination) > 2.0 => destination,
+                    _ => {
+                        if !rng.rng.gen_bool(0.01) {
+                            continue;
+                        }
+                        let destination = random_point(&mut rng, civilian.home_min, civilian.home_max);
+                        civilian.destination = Some(destination);
+                        destination
+                    }
+                };
+                (destination - position.0).clamp_length_max(WALK_SPEED * delta)
+            }
+            // Anything else keeps them where they are
+            _ => continue,
+        };
+
+        let next = position.0 + step;
+        if walkable(&grid, next) {
+            position.0 = next;
+        } else if chosen.action == Action::Flee {
+            // Pinned against a wall or roadblock: slide along it, or run sideways round it
+            let flat = step.truncate();
+            let detours = [Vec2::new(flat.x, 0.0), Vec2::new(0.0, flat.y), flat.perp(), -flat.perp()];
+            if let Some(detour) = detours.into_iter()
+                .filter(|detour| *detour != Vec2::ZERO)
+                .map(|detour| position.0 + detour.extend(0.0))
+                .find(|detour| walkable(&grid, *detour))
+            {
+                position.0 = detour;
+            }
+        } else {
+            // Walked into a wall or roadblock: give up on this destination
+            civilian.destination = None;
+        }
+    }
+}
+
+fn walkable(grid: &NavGrid, position: Vec3) -> bool {
+    grid.contains(position) && !grid.is_blocked(grid.world_to_cell(position))
+}
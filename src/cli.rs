@@ -0,0 +1,106 @@
+// ==================== COMMAND LINE ====================
+
+use crate::difficulty::Difficulty;
+use crate::sim::PlayerSide;
+
+#[derive(Debug)]
+pub struct CliArgs {
+    pub seed: Option<u64>,
+    pub headless: bool,
+    pub seeds: u32,
+    pub duration: f32,
+    pub load: Option<String>,
+    // Side the human plays; unset means the cartel in a window and AI-vs-AI headless
+    pub side: Option<PlayerSide>,
+    // Mission scenario file; unset plays the historical scenario
+    pub scenario: Option<String>,
+    // Military wave schedule file; unset uses the historical schedule
+    pub waves: Option<String>,
+    // Difficulty preset; unset means Historical, or the one picked on the briefing screen
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            headless: false,
+            seeds: 1,
+            duration: 600.0,
+            load: None,
+            side: None,
+            scenario: None,
+            waves: None,
+            difficulty: None,
+        }
+    }
+}
+
+const USAGE: &str = "usage: culiacan-rts [--seed <u64>] [--load <save.json>] [--side <cartel|military|none>] [--scenario <scenario.json>] [--waves <waves.json>] [--difficulty <recruit|historical|veteran>] [--headless [--seeds <n>] [--duration <seconds>]]";
+
+impl CliArgs {
+    pub fn parse() -> Self {
+        match Self::parse_from(std::env::args().skip(1)) {
+            Ok(args) => args,
+            Err(message) => {
+                eprintln!("{}\n{}", message, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    fn parse_from(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = CliArgs::default();
+        let mut args = args;
+
+        while let Some(arg) = args.next() {
+            // Accept both "--flag value" and "--flag=value"
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+
+            match flag.as_str() {
+                "--seed" => {
+                    let raw = value("--seed")?;
+                    parsed.seed = Some(raw.parse().map_err(|_| format!("invalid seed '{}'", raw))?);
+                }
+                "--headless" => parsed.headless = true,
+                "--seeds" => {
+                    let raw = value("--seeds")?;
+                    parsed.seeds = raw.parse().ok().filter(|n| *n > 0)
+                        .ok_or_else(|| format!("invalid seed count '{}'", raw))?;
+                }
+                "--duration" => {
+                    let raw = value("--duration")?;
+                    parsed.duration = raw.parse().ok().filter(|d: &f32| *d > 0.0)
+                        .ok_or_else(|| format!("invalid duration '{}'", raw))?;
+                }
+                "--load" => parsed.load = Some(value("--load")?),
+                "--scenario" => parsed.scenario = Some(value("--scenario")?),
+                "--waves" => parsed.waves = Some(value("--waves")?),
+                "--difficulty" => {
+                    let raw = value("--difficulty")?;
+                    parsed.difficulty = Some(raw.parse().map_err(|_| format!("invalid difficulty '{}'", raw))?);
+                }
+                "--side" => {
+                    let raw = value("--side")?;
+                    parsed.side = Some(raw.parse().map_err(|_| format!("invalid side '{}'", raw))?);
+                }
+                "--help" | "-h" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                other => return Err(format!("unknown argument '{}'", other)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
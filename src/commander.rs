@@ -0,0 +1,177 @@
+// ==================== CARTEL COMMANDER ====================
+// Runs the cartel when the human plays the military, or nobody does. Every
+// few seconds it sizes up the fight and queues the same commands a player
+// would: roadblocks at chokepoints on the military's approach, reinforcements
+// when the defence thins out, defenders pulled in around Ovidio, and a run
+// down the safest escape route once the safehouse is about to fall.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::difficulty::DifficultyPreset;
+use crate::capture::OvidioStatus;
+use crate::economy::{CartelAction, CartelResources, EconomyRules};
+use crate::map::MapLayout;
+use crate::nav::NavGrid;
+use crate::ovidio::OvidioMetadata;
+use crate::placement::{placement_problem, roadblock_site};
+use crate::sim::{PlayerCommand, PlayerCommands, PlayerSide};
+use crate::{Faction, GameState, Movement, Unit, UnitType, WorldPosition};
+
+// Below this many gunmen the commander saves up for reinforcements
+const MIN_DEFENDERS: usize = 8;
+const MAX_ROADBLOCKS: usize = 8;
+const ROADBLOCK_SPACING: f32 = 100.0;
+// Roadblocks go on the approach, not on the doorstep or out of town
+const ROADBLOCK_MIN_DISTANCE: f32 = 100.0;
+const ROADBLOCK_MAX_DISTANCE: f32 = 450.0;
+// Idle gunmen further than this from their post are called back
+const DEFENSE_RADIUS: f32 = 120.0;
+// The post sits this far out from Ovidio toward the nearest soldier
+const SCREEN_DISTANCE: f32 = 60.0;
+// Ovidio runs once soldiers this close outnumber his guards, as long as none
+// is already near enough to shoot him on the way out
+const DANGER_RADIUS: f32 = 350.0;
+const CLOSE_RADIUS: f32 = 150.0;
+const GUARD_RADIUS: f32 = 100.0;
+
+#[derive(Resource, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct CartelCommander {
+    // Seconds until the next round of orders
+    pub think_in: f32,
+    pub escape_ordered: bool,
+}
+
+// Escape route whose end has no soldiers around it, easiest first
+fn safest_route(metadata: &OvidioMetadata, layout: &MapLayout, soldiers: &[Vec3]) -> Option<usize> {
+    metadata.capture_mechanics.escape_routes.iter()
+        .enumerate()
+        .filter_map(|(index, route)| {
+            let destination = layout.landmark(route.destination_id())?;
+            let threats = soldiers.iter().filter(|soldier| soldier.distance(destination) <= DANGER_RADIUS).count();
+            (threats == 0).then_some((index, route.difficulty))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+// Road spot that costs the soldiers the least detour to block, so it sits
+// right on their way to `home`
+fn best_chokepoint<'a>(
+    layout: &MapLayout,
+    grid: &NavGrid,
+    units: &[(&'a Unit, &'a WorldPosition)],
+    roadblocks: &[Vec3],
+    soldiers: &[Vec3],
+    home: Vec3,
+) -> Option<(Vec3, f32)> {
+    let segments = layout.road_segments();
+    segments.iter()
+        .flat_map(|&(a, b)| [a, (a + b) * 0.5, b])
+        .map(|candidate| roadblock_site(&segments, candidate))
+        .filter(|(site, _)| (ROADBLOCK_MIN_DISTANCE..=ROADBLOCK_MAX_DISTANCE).contains(&site.distance(home)))
+        .filter(|(site, _)| roadblocks.iter().all(|roadblock| roadblock.distance(*site) > ROADBLOCK_SPACING))
+        .filter(|(site, _)| placement_problem(grid, units.iter().copied(), *site).is_none())
+        .filter_map(|(site, heading)| {
+            let detour = soldiers.iter()
+                .map(|soldier| soldier.distance(site) + site.distance(home) - soldier.distance(home))
+                .min_by(f32::total_cmp)?;
+            Some((site, heading, detour))
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(site, heading, _)| (site, heading))
+}
+
+// The state of the fight the commander reads before giving orders
+#[derive(SystemParam)]
+pub struct CommanderIntel<'w> {
+    game_state: Res<'w, GameState>,
+    metadata: Res<'w, OvidioMetadata>,
+    layout: Res<'w, MapLayout>,
+    grid: Res<'w, NavGrid>,
+    rules: Res<'w, EconomyRules>,
+    resources: Res<'w, CartelResources>,
+}
+
+pub fn cartel_commander_system(
+    time: Res<Time>,
+    side: Res<PlayerSide>,
+    mut commander: ResMut<CartelCommander>,
+    preset: Res<DifficultyPreset>,
+    intel: CommanderIntel,
+    mut player_commands: ResMut<PlayerCommands>,
+    unit_query: Query<(Entity, &Unit, &WorldPosition, Option<&Movement>)>,
+) {
+    if !side.is_ai(&Faction::Cartel) {
+        return;
+    }
+    let CommanderIntel { game_state, metadata, layout, grid, rules, resources } = intel;
+    commander.think_in -= time.delta_seconds();
+    if commander.think_in > 0.0 {
+        return;
+    }
+    // Seconds between rounds of orders depend on the difficulty
+    commander.think_in += preset.commander_interval;
+
+    let mut gunmen = Vec::new();
+    let mut soldiers = Vec::new();
+    let mut roadblocks = Vec::new();
+    let mut ovidio = None;
+    for (entity, unit, position, movement) in unit_query.iter() {
+        match (&unit.faction, &unit.unit_type) {
+            (Faction::Cartel, UnitType::Roadblock) => roadblocks.push(position.0),
+            (Faction::Cartel, UnitType::Ovidio) => ovidio = Some(position.0),
+            (Faction::Cartel, _) => {
+                let idle = unit.target.is_none() && movement.is_some_and(|movement| movement.target_position.is_none());
+                gunmen.push((entity, position.0, idle));
+            }
+            (Faction::Military, _) => soldiers.push(position.0),
+            _ => {}
+        }
+    }
+    // Wherever Ovidio is, in custody or on the run, that is where the fight is
+    let home = ovidio.unwrap_or_else(|| layout.ovidio_position());
+
+    if game_state.ovidio_status == OvidioStatus::Free && !commander.escape_ordered {
+        let threats = soldiers.iter().filter(|soldier| soldier.distance(home) <= DANGER_RADIUS).count();
+        let guards = gunmen.iter().filter(|(_, position, _)| position.distance(home) <= GUARD_RADIUS).count();
+        let cornered = soldiers.iter().any(|soldier| soldier.distance(home) <= CLOSE_RADIUS);
+        if threats > guards && !cornered {
+            if let Some(route) = safest_route(&metadata, &layout, &soldiers) {
+                info!("📻 '¡Sáquenlo de ahí!' The cartel moves Ovidio out");
+                player_commands.push(PlayerCommand::Escape { route });
+                commander.escape_ordered = true;
+            }
+        }
+    }
+
+    // Idle gunmen form a screen between Ovidio and the nearest soldiers
+    let nearest_threat = soldiers.iter().min_by(|a, b| a.distance(home).total_cmp(&b.distance(home)));
+    let post = match nearest_threat {
+        Some(threat) => home + (*threat - home).normalize_or_zero() * SCREEN_DISTANCE,
+        None => home,
+    };
+    let stragglers: Vec<Entity> = gunmen.iter()
+        .filter(|(_, position, idle)| *idle && position.distance(post) > DEFENSE_RADIUS)
+        .map(|(entity, _, _)| *entity)
+        .collect();
+    if !stragglers.is_empty() {
+        player_commands.push(PlayerCommand::Move { units: stragglers, target: post });
+    }
+
+    // Men first; roadblocks only once the defence is up to strength
+    if gunmen.len() < MIN_DEFENDERS {
+        if resources.can_afford(&rules, CartelAction::Reinforcements) {
+            player_commands.push(PlayerCommand::CallReinforcements);
+        }
+    } else if !soldiers.is_empty()
+        && roadblocks.len() < MAX_ROADBLOCKS
+        && resources.can_afford(&rules, CartelAction::Roadblock)
+    {
+        let units: Vec<_> = unit_query.iter().map(|(_, unit, position, _)| (unit, position)).collect();
+        if let Some((position, heading)) = best_chokepoint(&layout, &grid, &units, &roadblocks, &soldiers, home) {
+            player_commands.push(PlayerCommand::DeployRoadblock { position, heading });
+        }
+    }
+}
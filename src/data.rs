@@ -0,0 +1,113 @@
+// ==================== DATA FILES ====================
+// Every JSON file under assets/data is read the same way: parsed, checked for
+// values the game cannot use, and replaced by the copy compiled into the
+// binary when the file on disk is missing or broken.
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum DataError {
+    Io { path: String, source: std::io::Error },
+    Parse { path: String, source: serde_json::Error },
+    Invalid { path: String, problems: Vec<String> },
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::Io { path, source } => write!(f, "cannot read '{}': {}", path, source),
+            DataError::Parse { path, source } => write!(f, "invalid data in '{}': {}", path, source),
+            DataError::Invalid { path, problems } => write!(f, "'{}' is broken: {}", path, problems.join("; ")),
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+pub fn load_json<T: DeserializeOwned>(path: &str, validate: impl Fn(&T) -> Vec<String>) -> Result<T, DataError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| DataError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    parse_json(path, &contents, validate)
+}
+
+// `validate` lists what is wrong with the parsed value; any problem rejects it
+pub fn parse_json<T: DeserializeOwned>(
+    path: &str,
+    contents: &str,
+    validate: impl Fn(&T) -> Vec<String>,
+) -> Result<T, DataError> {
+    let value: T = serde_json::from_str(contents).map_err(|source| DataError::Parse {
+        path: path.to_string(),
+        source,
+    })?;
+    let problems = validate(&value);
+    if !problems.is_empty() {
+        return Err(DataError::Invalid {
+            path: path.to_string(),
+            problems,
+        });
+    }
+    Ok(value)
+}
+
+// Loads a data file from disk, falling back to the copy compiled into the binary
+pub fn load_json_or_builtin<T: DeserializeOwned>(
+    path: &str,
+    builtin: &str,
+    validate: impl Fn(&T) -> Vec<String>,
+) -> T {
+    match load_json(path, &validate) {
+        Ok(value) => value,
+        Err(err) => {
+            error!("❌ {}", err);
+            warn!("📂 Falling back to the built-in copy of {}", path);
+            parse_json("<built-in>", builtin, validate)
+                .unwrap_or_else(|err| panic!("built-in copy of {} must be valid: {}", path, err))
+        }
+    }
+}
+
+// For files serde already checks completely
+pub fn no_problems<T>(_: &T) -> Vec<String> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Sample {
+        count: i32,
+    }
+
+    fn non_negative(sample: &Sample) -> Vec<String> {
+        if sample.count < 0 {
+            vec![format!("count is {}", sample.count)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn parse_json_reports_syntax_and_validation_errors() {
+        assert_eq!(parse_json("ok", r#"{ "count": 2 }"#, non_negative).unwrap(), Sample { count: 2 });
+        assert!(matches!(parse_json("bad", "{", non_negative), Err(DataError::Parse { .. })));
+        match parse_json("negative", r#"{ "count": -1 }"#, non_negative) {
+            Err(DataError::Invalid { problems, .. }) => assert_eq!(problems, vec!["count is -1".to_string()]),
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn missing_or_invalid_files_fall_back_to_the_builtin_copy() {
+        assert!(matches!(load_json("does_not_exist.json", non_negative), Err(DataError::Io { .. })));
+        let sample: Sample = load_json_or_builtin("does_not_exist.json", r#"{ "count": 7 }"#, non_negative);
+        assert_eq!(sample, Sample { count: 7 });
+    }
+}
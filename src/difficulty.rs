@@ -0,0 +1,251 @@
+// ==================== DIFFICULTY ====================
+// Recruit, Historical and Veteran presets scale the military waves and unit
+// stats, how fast the AI reacts and what the cartel has to spend. The numbers
+// live in assets/data/difficulty_presets.json; a preset may also swap in its
+// own wave schedule. The preset is fixed when the match starts, whether it
+// was picked on the briefing screen or with `--difficulty`.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::data;
+use crate::economy::{CartelResources, EconomyRules};
+use crate::map::MapLayout;
+use crate::pressure::is_military_unit;
+use crate::unit_stats::UnitRegistry;
+use crate::waves::{self, WaveSchedule};
+use crate::{Unit, WaveSpawner};
+
+pub const DIFFICULTY_PRESETS_PATH: &str = "assets/data/difficulty_presets.json";
+
+const BUILTIN_DIFFICULTY_PRESETS: &str = include_str!("../assets/data/difficulty_presets.json");
+
+#[derive(Resource, Clone, Copy, Default, PartialEq, Eq, Hash, Debug, Deserialize)]
+pub enum Difficulty {
+    Recruit,
+    #[default]
+    Historical,
+    Veteran,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Recruit, Difficulty::Historical, Difficulty::Veteran];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Recruit => "recruit",
+            Difficulty::Historical => "historical",
+            Difficulty::Veteran => "veteran",
+        }
+    }
+}
+
+impl std::str::FromStr for Difficulty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "recruit" => Ok(Difficulty::Recruit),
+            "historical" => Ok(Difficulty::Historical),
+            "veteran" => Ok(Difficulty::Veteran),
+            _ => Err(()),
+        }
+    }
+}
+
+// What one difficulty level changes; every scale is a multiplier on the base numbers
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct DifficultyPreset {
+    pub description: String,
+    // Replaces the default wave schedule unless `--waves` names one
+    #[serde(default)]
+    pub wave_schedule: Option<String>,
+    pub wave_delay: f32,
+    pub wave_size: f32,
+    pub military_health: f32,
+    pub military_damage: f32,
+    // Seconds an AI unit takes to open fire on a new target
+    pub ai_reaction: f32,
+    // Seconds between rounds of orders from the cartel commander
+    pub commander_interval: f32,
+    pub cartel_money: f32,
+    pub cartel_income: f32,
+}
+
+impl DifficultyPreset {
+    fn problems(&self, difficulty: Difficulty) -> Vec<String> {
+        let scales = [
+            ("wave_delay", self.wave_delay),
+            ("wave_size", self.wave_size),
+            ("military_health", self.military_health),
+            ("military_damage", self.military_damage),
+            ("commander_interval", self.commander_interval),
+            ("cartel_money", self.cartel_money),
+            ("cartel_income", self.cartel_income),
+        ];
+        let mut problems: Vec<String> = scales.iter()
+            .filter(|(_, value)| *value <= 0.0)
+            .map(|(field, value)| format!("{:?} has {} {}, expected more than 0", difficulty, field, value))
+            .collect();
+        if self.ai_reaction < 0.0 {
+            problems.push(format!("{:?} has negative ai_reaction {}", difficulty, self.ai_reaction));
+        }
+        problems
+    }
+}
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct DifficultyPresets {
+    presets: HashMap<Difficulty, DifficultyPreset>,
+}
+
+impl DifficultyPresets {
+    pub fn get(&self, difficulty: Difficulty) -> &DifficultyPreset {
+        // Every level is checked when the presets are loaded
+        &self.presets[&difficulty]
+    }
+}
+
+// Holds fire for the unit's reaction time after it picks up a new target
+pub fn acquire_target(unit: &mut Unit, target: Entity, reaction: f32) {
+    if unit.target.is_none() {
+        let cooldown = unit.attack_cooldown.duration();
+        let wait = std::time::Duration::from_secs_f32(reaction).min(cooldown);
+        unit.attack_cooldown.set_elapsed(cooldown - wait);
+    }
+    unit.target = Some(target);
+}
+
+// ==================== MATCH START ====================
+
+// The match data a preset scales
+#[derive(SystemParam)]
+pub struct ScaledByDifficulty<'w> {
+    registry: ResMut<'w, UnitRegistry>,
+    rules: ResMut<'w, EconomyRules>,
+    resources: ResMut<'w, CartelResources>,
+    schedule: ResMut<'w, WaveSchedule>,
+}
+
+// Scales the stats, schedule and purse of the match that is about to start
+pub fn apply_difficulty_system(
+    mut commands: Commands,
+    difficulty: Res<Difficulty>,
+    presets: Res<DifficultyPresets>,
+    layout: Res<MapLayout>,
+    scaled: ScaledByDifficulty,
+    mut wave_query: Query<&mut WaveSpawner>,
+    mut unit_query: Query<&mut Unit>,
+) {
+    let ScaledByDifficulty { mut registry, mut rules, mut resources, mut schedule } = scaled;
+    let preset = presets.get(*difficulty);
+    info!("🎚️ Difficulty: {} - {}", difficulty.name(), preset.description);
+
+    registry.scale_military(preset.military_health, preset.military_damage);
+    for mut unit in unit_query.iter_mut().filter(|unit| is_military_unit(&unit.unit_type)) {
+        unit.max_health *= preset.military_health;
+        unit.health *= preset.military_health;
+        unit.damage *= preset.military_damage;
+    }
+
+    rules.starting_money *= preset.cartel_money;
+    rules.income.money_per_second *= preset.cartel_income;
+    rules.income.manpower_per_second *= preset.cartel_income;
+    *resources = CartelResources::new(&rules);
+
+    if let Some(path) = preset.wave_schedule.as_deref().filter(|_| !schedule.custom) {
+        *schedule = waves::load_wave_schedule_or_builtin(path);
+    }
+    for id in schedule.unknown_entry_points(&layout) {
+        warn!("🧭 Wave schedule '{}' uses unknown entry point '{}'", schedule.name, id);
+    }
+    for mut spawner in wave_query.iter_mut() {
+        let delay = schedule.wave(1).delay * preset.wave_delay;
+        spawner.next_wave_timer = Timer::from_seconds(delay, TimerMode::Repeating);
+    }
+
+    commands.insert_resource(preset.clone());
+}
+
+// ==================== LOADING ====================
+
+impl DifficultyPresets {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for difficulty in Difficulty::ALL {
+            match self.presets.get(&difficulty) {
+                Some(preset) => problems.extend(preset.problems(difficulty)),
+                None => problems.push(format!("no preset for {:?}", difficulty)),
+            }
+        }
+        problems
+    }
+}
+
+pub fn load_difficulty_presets_or_builtin(path: &str) -> DifficultyPresets {
+    data::load_json_or_builtin(path, BUILTIN_DIFFICULTY_PRESETS, DifficultyPresets::problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataError;
+
+    fn parse(contents: &str) -> Result<DifficultyPresets, DataError> {
+        data::parse_json("test", contents, DifficultyPresets::problems)
+    }
+
+    fn historical() -> DifficultyPreset {
+        parse(BUILTIN_DIFFICULTY_PRESETS).expect("built-in difficulty presets must be valid").get(Difficulty::Historical).clone()
+    }
+
+    fn problems_of(result: Result<DifficultyPresets, DataError>) -> Vec<String> {
+        match result {
+            Err(DataError::Invalid { problems, .. }) => problems,
+            other => panic!("expected the presets to be rejected, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn builtin_presets_cover_every_difficulty() {
+        let presets = parse(BUILTIN_DIFFICULTY_PRESETS).unwrap();
+        for difficulty in Difficulty::ALL {
+            assert!(presets.get(difficulty).problems(difficulty).is_empty());
+        }
+    }
+
+    #[test]
+    fn problems_flag_non_positive_scales_and_negative_reaction() {
+        let mut preset = historical();
+        assert!(preset.problems(Difficulty::Historical).is_empty());
+
+        preset.wave_delay = 0.0;
+        preset.cartel_income = -1.0;
+        preset.ai_reaction = -0.5;
+        let problems = preset.problems(Difficulty::Veteran);
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].starts_with("Veteran has wave_delay 0"));
+        assert!(problems[1].contains("cartel_income"));
+        assert!(problems[2].contains("ai_reaction"));
+    }
+
+    #[test]
+    fn parsing_rejects_missing_and_broken_presets() {
+        let historical = r#"{
+            "description": "", "wave_delay": 1, "wave_size": 1, "military_health": 1, "military_damage": 1,
+            "ai_reaction": 0.5, "commander_interval": 2, "cartel_money": 1, "cartel_income": 1
+        }"#;
+        let broken = historical.replace(r#""wave_size": 1"#, r#""wave_size": 0"#);
+        let file = format!(r#"{{ "Recruit": {}, "Historical": {} }}"#, historical, broken);
+
+        let problems = problems_of(parse(&file));
+        assert_eq!(problems, vec![
+            "Historical has wave_size 0, expected more than 0".to_string(),
+            "no preset for Veteran".to_string(),
+        ]);
+        assert!(matches!(parse(r#"{ "Recruit": { "wave_delay": 1 } }"#), Err(DataError::Parse { .. })));
+    }
+}
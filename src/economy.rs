@@ -0,0 +1,257 @@
+// ==================== CARTEL ECONOMY ====================
+// Roadblocks and reinforcements cost money and manpower and have cooldowns;
+// cartel-held zones pay income scaled by their strategic_value. The numbers
+// live in assets/data/cartel_economy.json so they can be tuned without a
+// recompile.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::data;
+use crate::map::Zone;
+use crate::zones::ZoneControl;
+use crate::Faction;
+
+pub const CARTEL_ECONOMY_PATH: &str = "assets/data/cartel_economy.json";
+
+const BUILTIN_CARTEL_ECONOMY: &str = include_str!("../assets/data/cartel_economy.json");
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct EconomyRules {
+    pub starting_money: f32,
+    pub starting_manpower: f32,
+    pub income: Income,
+    pub actions: ActionCosts,
+}
+
+// Paid every second for each cartel zone, times its strategic_value
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct Income {
+    pub money_per_second: f32,
+    pub manpower_per_second: f32,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct ActionCosts {
+    pub roadblock: ActionCost,
+    pub reinforcements: ActionCost,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct ActionCost {
+    pub money: f32,
+    pub manpower: f32,
+    // Seconds before the action can be used again
+    pub cooldown: f32,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CartelAction {
+    Roadblock,
+    Reinforcements,
+}
+
+impl ActionCosts {
+    pub fn get(&self, action: CartelAction) -> ActionCost {
+        match action {
+            CartelAction::Roadblock => self.roadblock,
+            CartelAction::Reinforcements => self.reinforcements,
+        }
+    }
+}
+
+// ==================== RESOURCES ====================
+
+#[derive(Resource, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct CartelResources {
+    pub money: f32,
+    pub manpower: f32,
+    pub roadblock_cooldown: f32,
+    pub reinforcements_cooldown: f32,
+}
+
+// Why an action was refused
+#[derive(Debug)]
+pub enum Denied {
+    Cooldown(f32),
+    Money(f32),
+    Manpower(f32),
+}
+
+impl fmt::Display for Denied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Denied::Cooldown(seconds) => write!(f, "ready again in {:.0}s", seconds.ceil()),
+            Denied::Money(missing) => write!(f, "${:.0} short", missing.ceil()),
+            Denied::Manpower(missing) => write!(f, "{:.0} men short", missing.ceil()),
+        }
+    }
+}
+
+impl CartelResources {
+    pub fn new(rules: &EconomyRules) -> Self {
+        Self {
+            money: rules.starting_money,
+            manpower: rules.starting_manpower,
+            ..default()
+        }
+    }
+
+    pub fn cooldown(&self, action: CartelAction) -> f32 {
+        match action {
+            CartelAction::Roadblock => self.roadblock_cooldown,
+            CartelAction::Reinforcements => self.reinforcements_cooldown,
+        }
+    }
+
+    // Whether try_spend would go through right now
+    pub fn can_afford(&self, rules: &EconomyRules, action: CartelAction) -> bool {
+        let cost = rules.actions.get(action);
+        self.cooldown(action) <= 0.0 && self.money >= cost.money && self.manpower >= cost.manpower
+    }
+
+    // Pays for an action and starts its cooldown, or says why it cannot be afforded
+    pub fn try_spend(&mut self, rules: &EconomyRules, action: CartelAction) -> Result<(), Denied> {
+        let cost = rules.actions.get(action);
+        let cooldown = self.cooldown(action);
+        if cooldown > 0.0 {
+            return Err(Denied::Cooldown(cooldown));
+        }
+        if self.money < cost.money {
+            return Err(Denied::Money(cost.money - self.money));
+        }
+        if self.manpower < cost.manpower {
+            return Err(Denied::Manpower(cost.manpower - self.manpower));
+        }
+
+        self.money -= cost.money;
+        self.manpower -= cost.manpower;
+        match action {
+            CartelAction::Roadblock => self.roadblock_cooldown = cost.cooldown,
+            CartelAction::Reinforcements => self.reinforcements_cooldown = cost.cooldown,
+        }
+        Ok(())
+    }
+}
+
+pub fn cartel_income_system(
+    time: Res<Time>,
+    rules: Res<EconomyRules>,
+    mut resources: ResMut<CartelResources>,
+    zone_query: Query<(&Zone, &ZoneControl)>,
+) {
+    let dt = time.delta_seconds();
+    resources.roadblock_cooldown = (resources.roadblock_cooldown - dt).max(0.0);
+    resources.reinforcements_cooldown = (resources.reinforcements_cooldown - dt).max(0.0);
+
+    let held_value: f32 = zone_query.iter()
+        .filter(|(_, control)| control.owner == Faction::Cartel)
+        .map(|(zone, _)| zone.strategic_value)
+        .sum();
+    resources.money += rules.income.money_per_second * held_value * dt;
+    resources.manpower += rules.income.manpower_per_second * held_value * dt;
+}
+
+// ==================== LOADING ====================
+
+pub fn load_economy_rules_or_builtin(path: &str) -> EconomyRules {
+    data::load_json_or_builtin(path, BUILTIN_CARTEL_ECONOMY, data::no_problems)
+}
+
+// ==================== HUD ====================
+
+#[derive(Component)]
+pub struct EconomyText;
+
+pub fn spawn_economy_hud(parent: &mut ChildBuilder) {
+    parent.spawn((
+        TextBundle::from_section(
+            "💰 $0 | 👥 0",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::rgb(0.6, 1.0, 0.6),
+                ..default()
+            },
+        ),
+        EconomyText,
+    ));
+}
+
+fn action_status(resources: &CartelResources, rules: &EconomyRules, action: CartelAction) -> String {
+    let cost = rules.actions.get(action);
+    let cooldown = resources.cooldown(action);
+    let price = if cost.manpower > 0.0 {
+        format!("${:.0}+{:.0}👥", cost.money, cost.manpower)
+    } else {
+        format!("${:.0}", cost.money)
+    };
+    if cooldown > 0.0 {
+        format!("{} ({:.0}s)", price, cooldown.ceil())
+    } else {
+        price
+    }
+}
+
+pub fn economy_hud_system(
+    rules: Res<EconomyRules>,
+    resources: Res<CartelResources>,
+    mut text_query: Query<&mut Text, With<EconomyText>>,
+) {
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!(
+            "💰 ${:.0} | 👥 {:.0} | SPACE 🚧 {} | R 📞 {}",
+            resources.money.floor(),
+            resources.manpower.floor(),
+            action_status(&resources, &rules, CartelAction::Roadblock),
+            action_status(&resources, &rules, CartelAction::Reinforcements),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> EconomyRules {
+        let cost = |money, manpower, cooldown| ActionCost { money, manpower, cooldown };
+        EconomyRules {
+            starting_money: 2000.0,
+            starting_manpower: 2.0,
+            income: Income { money_per_second: 0.0, manpower_per_second: 0.0 },
+            actions: ActionCosts {
+                roadblock: cost(1500.0, 0.0, 8.0),
+                reinforcements: cost(1000.0, 3.0, 30.0),
+            },
+        }
+    }
+
+    #[test]
+    fn spending_pays_and_starts_the_cooldown() {
+        let rules = rules();
+        let mut resources = CartelResources::new(&rules);
+        assert!(resources.try_spend(&rules, CartelAction::Roadblock).is_ok());
+        assert_eq!(resources.money, 500.0);
+        assert_eq!(resources.roadblock_cooldown, 8.0);
+        assert_eq!(resources.reinforcements_cooldown, 0.0);
+    }
+
+    #[test]
+    fn denials_say_why_and_charge_nothing() {
+        let rules = rules();
+        let mut resources = CartelResources::new(&rules);
+
+        // Enough money, but only two of the three men
+        assert!(matches!(resources.try_spend(&rules, CartelAction::Reinforcements), Err(Denied::Manpower(missing)) if missing == 1.0));
+        assert_eq!(resources.money, 2000.0);
+        assert!(!resources.can_afford(&rules, CartelAction::Reinforcements));
+
+        resources.try_spend(&rules, CartelAction::Roadblock).unwrap();
+        assert!(matches!(resources.try_spend(&rules, CartelAction::Roadblock), Err(Denied::Cooldown(seconds)) if seconds == 8.0));
+
+        resources.roadblock_cooldown = 0.0;
+        assert!(matches!(resources.try_spend(&rules, CartelAction::Roadblock), Err(Denied::Money(missing)) if missing == 1000.0));
+        assert_eq!(resources.money, 500.0);
+        assert_eq!(resources.roadblock_cooldown, 0.0);
+    }
+}
@@ -0,0 +1,263 @@
+// ==================== HEADLESS BALANCE RUNS ====================
+// `culiacan-rts --headless --seeds N --duration S` runs the simulation without
+// a window, renderer or audio, stepping fixed ticks as fast as possible, and
+// prints a JSON summary of every run to stdout. Both sides are left to the AI
+// unless `--side` hands one of them to a (here idle) player.
+
+use bevy::app::PluginsState;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::civilians::CivilianCasualties;
+use crate::difficulty::Difficulty;
+use crate::cli::CliArgs;
+use crate::economy::CartelResources;
+use crate::map::Zone;
+use crate::mission::MissionScript;
+use crate::pressure::PoliticalPressure;
+use crate::save::SaveRequests;
+use crate::sim::{PlayerSide, SIM_HZ};
+use crate::strategic::StrategicPoint;
+use crate::screens::AppState;
+use crate::waves::WaveSchedule;
+use crate::zones::{CityControl, ZoneControl};
+use crate::{Faction, GameState, MatchStats, SimulationPlugin};
+
+#[derive(Serialize)]
+struct RunSummary {
+    seed: u64,
+    winner: Option<String>,
+    outcome: Option<String>,
+    time: f32,
+    cartel_score: u32,
+    military_score: u32,
+    waves_reached: u32,
+    phase_reached: String,
+    units_lost: BTreeMap<String, u32>,
+    civilian_casualties: CivilianCasualties,
+    political_pressure: f32,
+    points_held: BTreeMap<String, u32>,
+    city_control: CityControl,
+    cartel_resources: CartelResources,
+}
+
+// Difficulty, scenario and wave schedule the runs played, after any fallback to the built-ins
+#[derive(Serialize, Default)]
+struct MatchSetup {
+    difficulty: &'static str,
+    scenario: String,
+    wave_schedule: String,
+}
+
+#[derive(Serialize)]
+struct BatchSummary {
+    duration: f32,
+    player_side: &'static str,
+    #[serde(flatten)]
+    setup: MatchSetup,
+    cartel_wins: u32,
+    military_wins: u32,
+    undecided: u32,
+    runs: Vec<RunSummary>,
+}
+
+pub fn run(args: &CliArgs, base_seed: u64) {
+    let side = args.side.unwrap_or(PlayerSide::Spectator);
+    let mut setup = MatchSetup::default();
+    let runs: Vec<RunSummary> = (0..args.seeds as u64)
+        .map(|i| run_match(base_seed.wrapping_add(i), args, side, &mut setup))
+        .collect();
+
+    let count = |faction: &str| runs.iter().filter(|run| run.winner.as_deref() == Some(faction)).count() as u32;
+    let summary = BatchSummary {
+        duration: args.duration,
+        player_side: side.name(),
+        setup,
+        cartel_wins: count("Cartel"),
+        military_wins: count("Military"),
+        undecided: runs.iter().filter(|run| run.winner.is_none()).count() as u32,
+        runs,
+    };
+
+    println!("{}", serde_json::to_string_pretty(&summary).expect("summary serializes"));
+}
+
+fn run_match(seed: u64, args: &CliArgs, side: PlayerSide, setup: &mut MatchSetup) -> RunSummary {
+    let mut app = match_app(seed, args, side);
+    let max_ticks = (args.duration as f64 * SIM_HZ).ceil() as u64 + 1;
+    for _ in 0..max_ticks {
+        if step(&mut app, args.duration) {
+            break;
+        }
+    }
+    summarize(&mut app, seed, setup)
+}
+
+fn match_app(seed: u64, args: &CliArgs, side: PlayerSide) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(SimulationPlugin {
+            seed,
+            side,
+            scenario: args.scenario.clone(),
+            waves: args.waves.clone(),
+            difficulty: args.difficulty.unwrap_or_default(),
+        })
+        // No menus without a window: start straight in the match
+        .insert_resource(NextState(Some(AppState::InGame)))
+        .insert_resource(SaveRequests {
+            save: None,
+            load: args.load.clone(),
+        })
+        // Every update advances exactly one fixed step of simulated time
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / SIM_HZ)));
+
+    while app.plugins_state() == PluginsState::Adding {
+        std::thread::yield_now();
+    }
+    app.finish();
+    app.cleanup();
+    app
+}
+
+// Runs one fixed tick; true once the match is decided or out of time
+fn step(app: &mut App, duration: f32) -> bool {
+    app.update();
+    let game_state = app.world.resource::<GameState>();
+    game_state.outcome.is_some() || game_state.mission_timer >= duration
+}
+
+fn summarize(app: &mut App, seed: u64, setup: &mut MatchSetup) -> RunSummary {
+    let mut points_held = BTreeMap::new();
+    for point in app.world.query::<&StrategicPoint>().iter(&app.world) {
+        if let Some(owner) = &point.control.owner {
+            *points_held.entry(format!("{:?}", owner)).or_default() += 1;
+        }
+    }
+
+    let city_control = CityControl::evaluate(app.world.query::<(&Zone, &ZoneControl)>().iter(&app.world));
+
+    setup.difficulty = app.world.resource::<Difficulty>().name();
+    setup.scenario.clone_from(&app.world.resource::<MissionScript>().name);
+    setup.wave_schedule.clone_from(&app.world.resource::<WaveSchedule>().name);
+    let game_state = app.world.resource::<GameState>();
+    let stats = app.world.resource::<MatchStats>();
+    let pressure = app.world.resource::<PoliticalPressure>();
+    let resources = app.world.resource::<CartelResources>();
+    RunSummary {
+        seed,
+        winner: game_state.outcome.map(|outcome| match outcome.winner() {
+            Faction::Cartel => "Cartel".to_string(),
+            Faction::Military => "Military".to_string(),
+            Faction::Civilian => "Civilian".to_string(),
+        }),
+        outcome: game_state.outcome.map(|outcome| format!("{:?}", outcome)),
+        time: game_state.mission_timer,
+        cartel_score: game_state.cartel_score,
+        military_score: game_state.military_score,
+        waves_reached: game_state.current_wave,
+        phase_reached: format!("{:?}", game_state.game_phase),
+        units_lost: stats
+            .units_lost
+            .iter()
+            .map(|(unit_type, count)| (format!("{:?}", unit_type), *count))
+            .collect(),
+        civilian_casualties: game_state.civilian_casualties,
+        political_pressure: pressure.value,
+        points_held,
+        city_control,
+        cartel_resources: resources.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::SimRng;
+    use crate::WorldPosition;
+
+    fn play(seed: u64, args: &CliArgs, save_at: Option<(u64, &str)>) -> String {
+        let mut app = match_app(seed, args, PlayerSide::Spectator);
+        // A second to spare, so runs stop on the match clock rather than on the tick count
+        let max_ticks = ((args.duration as f64 + 1.0) * SIM_HZ).ceil() as u64;
+        for tick in 0..max_ticks {
+            if let Some((_, path)) = save_at.filter(|(at, _)| *at == tick) {
+                app.world.resource_mut::<SaveRequests>().save = Some(path.to_string());
+            }
+            if step(&mut app, args.duration) {
+                break;
+            }
+        }
+        // Where everyone stands and how far the RNG got, beyond what the summary reports
+        let mut positions: Vec<[f32; 3]> = app.world.query::<&WorldPosition>().iter(&app.world)
+            .map(|position| position.0.to_array())
+            .collect();
+        positions.sort_by(|a, b| a.partial_cmp(b).expect("positions are finite"));
+        let rng = app.world.resource::<SimRng>().state();
+        let summary = summarize(&mut app, seed, &mut MatchSetup::default());
+        serde_json::to_string(&(summary, positions, rng)).expect("summary serializes")
+    }
+
+    #[test]
+    fn saving_does_not_change_a_seeded_run() {
+        let args = CliArgs {
+            duration: 60.0,
+            ..default()
+        };
+        let path = std::env::temp_dir().join("culiacan-rts-save-replay-test.json");
+        let path = path.to_str().expect("temp path is UTF-8");
+        let _ = std::fs::remove_file(path);
+
+        let plain = play(7, &args, None);
+        let saved = play(7, &args, Some((20 * SIM_HZ as u64, path)));
+
+        assert!(std::path::Path::new(path).exists(), "the save was not written");
+        let _ = std::fs::remove_file(path);
+        assert_eq!(plain, saved);
+    }
+
+    #[test]
+    fn a_loaded_match_continues_like_the_saved_one() {
+        let args = CliArgs {
+            duration: 60.0,
+            ..default()
+        };
+        let path = std::env::temp_dir().join("culiacan-rts-load-replay-test.json");
+        let path = path.to_str().expect("temp path is UTF-8");
+        let _ = std::fs::remove_file(path);
+
+        // Mid-firefight, with units dying and retargeting around the save
+        let saved = play(7, &args, Some((5 * SIM_HZ as u64, path)));
+        let loaded = play(7, &CliArgs { load: Some(path.to_string()), ..args }, None);
+
+        let _ = std::fs::remove_file(path);
+        assert_eq!(saved, loaded);
+    }
+
+    #[test]
+    fn custody_lasts_until_extraction() {
+        let args = CliArgs {
+            duration: 60.0,
+            ..default()
+        };
+        let mut app = match_app(7, &args, PlayerSide::Spectator);
+        let mut detained = false;
+        let max_ticks = (args.duration as f64 * SIM_HZ).ceil() as u64 + 1;
+        for _ in 0..max_ticks {
+            let finished = step(&mut app, args.duration);
+            detained |= app.world.resource::<GameState>().ovidio_status.in_custody();
+            if finished {
+                break;
+            }
+        }
+
+        let game_state = app.world.resource::<GameState>();
+        assert!(detained, "the soldiers never took Ovidio");
+        // Extraction takes minutes, so the cartel still has time to win him back
+        assert_eq!(game_state.outcome, None);
+        assert!(game_state.mission_timer > args.duration - 1.0, "match stopped at {}s", game_state.mission_timer);
+    }
+}
@@ -6,15 +6,65 @@
 // attempt to capture Ovidio Guzmán López in Culiacán, Mexico.
 // =====================================================================
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use bevy_kira_audio::prelude::{Audio as KiraAudio, AudioSource as KiraAudioSource, AudioPlugin as KiraAudioPlugin};
-use rand::{thread_rng, Rng};
+use bevy_kira_audio::prelude::{AudioSource as KiraAudioSource, AudioPlugin as KiraAudioPlugin};
+use rand::Rng;
+use std::str::FromStr;
 use std::time::Duration;
 
-// ==================== AUDIO SYSTEM ====================
+mod audio;
+mod behavior;
+mod capture;
+mod civilians;
+mod commander;
+mod cli;
+mod data;
+mod difficulty;
+mod economy;
+mod headless;
+mod map;
+mod mission;
+mod nav;
+mod ovidio;
+mod placement;
+mod pressure;
+mod save;
+mod screens;
+mod selection;
+mod sim;
+mod squads;
+mod strategic;
+mod unit_stats;
+mod vehicles;
+mod waves;
+mod zones;
 
-// For now using procedural audio through console logging
-// Future: Real audio files with Handle<KiraAudioSource>
+use audio::{load_sound, SoundEvent};
+use capture::OvidioStatus;
+use civilians::CivilianCasualties;
+use economy::{load_economy_rules_or_builtin, CartelAction, CartelResources, EconomyRules, CARTEL_ECONOMY_PATH};
+use map::{iso_extent, load_map_or_builtin, MapLayout, Zone, MAP_LAYOUT_PATH};
+use nav::{NavGrid, NavPath, ROADBLOCK_BLOCK_RADIUS};
+use ovidio::{load_ovidio_metadata_or_builtin, OvidioMetadata, OVIDIO_METADATA_PATH};
+use pressure::PoliticalPressure;
+use save::{SaveRequests, QUICKSAVE_PATH};
+use screens::{AppState, ScreensPlugin};
+use placement::RoadblockPlacement;
+use behavior::{Action, AiDebug, BehaviorLibrary, Decision, DecisionLabel, Situation};
+use selection::{AttackOrder, DragSelection};
+use squads::{SquadMember, Squads, FIRETEAM_SIZE};
+use serde::{Deserialize, Serialize};
+use commander::CartelCommander;
+use mission::{MissionProgress, MissionScript};
+use sim::{PlayerCommand, PlayerCommands, PlayerSide, ShotFired, SimRng, SIM_HZ};
+use std::collections::HashMap;
+use strategic::{StrategicPoint, StrategicPointLabel};
+use unit_stats::{load_unit_registry_or_builtin, UnitRegistry, UNIT_STATS_PATH};
+use vehicles::{RoadNetwork, Vehicle};
+use waves::WaveSchedule;
+use difficulty::{Difficulty, DifficultyPreset};
+use zones::ZoneControl;
 
 // ==================== ISOMETRIC SYSTEM ====================
 
@@ -28,8 +78,30 @@ fn world_to_iso(world_pos: Vec3) -> Vec3 {
     Vec3::new(x, y, world_pos.z)
 }
 
+// Screen rotation of something pointing along `heading` (radians) on the map
+fn iso_rotation(heading: f32) -> Quat {
+    let direction = world_to_iso(Vec3::new(heading.cos(), heading.sin(), 0.0));
+    Quat::from_rotation_z(direction.y.atan2(direction.x))
+}
+
+// Inverse of world_to_iso, used to bring projected positions back onto the map
+fn iso_to_world(iso_pos: Vec3) -> Vec3 {
+    let x = iso_pos.x + iso_pos.y / 0.6;
+    let y = iso_pos.y / 0.6 - iso_pos.x;
+    Vec3::new(x, y, iso_pos.z)
+}
+
 // ==================== COMPONENTS ====================
 
+// Simulation position on the flat map. Gameplay systems only read and write
+// this; render_sync_system projects it into the Transform every frame.
+#[derive(Component, Clone, Copy, Default, Debug)]
+struct WorldPosition(Vec3);
+
+// Map heading in radians, for things that point somewhere (roadblocks across a road)
+#[derive(Component, Clone, Copy, Default, Debug)]
+struct Facing(f32);
+
 #[derive(Component, Clone)]
 struct Unit {
     health: f32,
@@ -70,7 +142,6 @@ struct ParticleEffect {
 struct WaveSpawner {
     next_wave_timer: Timer,
     wave_number: u32,
-    units_in_wave: u32,
 }
 
 #[derive(Component)]
@@ -93,21 +164,25 @@ struct WaveText;
 #[derive(Component)]
 struct ScoreText;
 
+#[derive(Component)]
+struct ControlsText;
+
 // ==================== ENUMS & TYPES ====================
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 enum Faction {
     Cartel,
     Military,
     Civilian,
 }
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 enum UnitType {
     // Cartel units
     Sicario,
     Enforcer,
     Roadblock,
+    NarcoTechTruck, // Armed pickup
     // Military units  
     Soldier,
     SpecialForces,
@@ -116,7 +191,31 @@ enum UnitType {
     Ovidio, // High value target
 }
 
-#[derive(Clone, PartialEq, Debug)]
+impl UnitType {
+    const NAMES: [&'static str; 8] = [
+        "Sicario", "Enforcer", "Roadblock", "NarcoTechTruck", "Soldier", "SpecialForces", "Vehicle", "Ovidio",
+    ];
+}
+
+impl FromStr for UnitType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Sicario" => Ok(UnitType::Sicario),
+            "Enforcer" => Ok(UnitType::Enforcer),
+            "Roadblock" => Ok(UnitType::Roadblock),
+            "NarcoTechTruck" => Ok(UnitType::NarcoTechTruck),
+            "Soldier" | "Infantry" => Ok(UnitType::Soldier),
+            "SpecialForces" => Ok(UnitType::SpecialForces),
+            "Vehicle" | "TacticalConvoy" => Ok(UnitType::Vehicle),
+            "Ovidio" => Ok(UnitType::Ovidio),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 enum ObjectiveType {
     Safehouse,      // Cartel must defend
     ExtractionPoint, // Military tries to reach
@@ -127,7 +226,9 @@ enum ObjectiveType {
 
 // ==================== RESOURCES ====================
 
+// Sprites, textures and the font are placeholders until the art files exist
 #[derive(Resource)]
+#[allow(dead_code)]
 struct GameAssets {
     // Unit sprites
     sicario_sprite: Handle<Image>,
@@ -146,10 +247,13 @@ struct GameAssets {
     // Fonts
     main_font: Handle<Font>,
     
-    // Audio
-    gunshot_sound: Handle<KiraAudioSource>,
-    explosion_sound: Handle<KiraAudioSource>,
-    radio_chatter: Handle<KiraAudioSource>,
+    // Audio (None when the file is missing)
+    military_gunfire_sound: Option<Handle<KiraAudioSource>>,
+    cartel_gunfire_sound: Option<Handle<KiraAudioSource>>,
+    death_sound: Option<Handle<KiraAudioSource>>,
+    explosion_sound: Option<Handle<KiraAudioSource>>,
+    radio_chatter: Option<Handle<KiraAudioSource>>,
+    construction_sound: Option<Handle<KiraAudioSource>>,
 }
 
 #[derive(Resource)]
@@ -159,17 +263,65 @@ struct GameState {
     cartel_score: u32,
     military_score: u32,
     game_phase: GamePhase,
-    ovidio_captured: bool,
+    ovidio_status: OvidioStatus,
+    outcome: Option<MatchOutcome>,
+    civilian_casualties: CivilianCasualties,
+    mission: MissionProgress,
+    // Set by the mission script to end the match on its own terms
+    scripted_outcome: Option<MatchOutcome>,
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 enum GamePhase {
     Preparation,    // Initial setup
     InitialRaid,   // Mission 1: Defend safehouse
     BlockConvoy,   // Mission 2: Block extraction
     ApplyPressure, // Mission 3: Escalate pressure
     HoldTheLine,   // Mission 4: Final showdown
-    GameOver,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Deserialize)]
+enum MatchOutcome {
+    OvidioKilled,      // Ovidio died in the crossfire
+    MilitaryVictory,   // Target captured and extracted
+    OvidioEscaped,     // Cartel moves Ovidio out along an escape route
+    HistoricalRelease, // Government releases Ovidio
+    MissionFailed,     // A mission phase failure condition was met
+}
+
+impl MatchOutcome {
+    fn winner(&self) -> Faction {
+        match self {
+            MatchOutcome::OvidioKilled | MatchOutcome::MilitaryVictory | MatchOutcome::MissionFailed => Faction::Military,
+            MatchOutcome::OvidioEscaped | MatchOutcome::HistoricalRelease => Faction::Cartel,
+        }
+    }
+    
+    fn headline(&self) -> &'static str {
+        match self {
+            MatchOutcome::OvidioKilled => "💀 DEFEAT: Ovidio Guzmán López was killed in the operation",
+            MatchOutcome::MilitaryVictory => "🎖️ MILITARY VICTORY: Target captured and extracted",
+            MatchOutcome::OvidioEscaped => "🛣️ ESCAPE: Ovidio slips out of Culiacán",
+            MatchOutcome::HistoricalRelease => "🏆 HISTORICAL OUTCOME: Government releases Ovidio to prevent casualties",
+            MatchOutcome::MissionFailed => "❌ MISSION FAILED: The cartel loses its grip on Culiacán",
+        }
+    }
+    
+    fn epilogue(&self) -> &'static str {
+        match self {
+            MatchOutcome::OvidioKilled => "⚰️ The operation ends in bloodshed and neither side gets what it wanted",
+            MatchOutcome::MilitaryVictory => "🚁 Ovidio is flown out of Culiacán - history takes a different turn",
+            MatchOutcome::OvidioEscaped => "🕶️ The cordon closes on an empty house - the target is gone",
+            MatchOutcome::HistoricalRelease => "📰 'El Culiacanazo' - Cartel demonstrates power over the state",
+            MatchOutcome::MissionFailed => "🚔 The operation goes to plan and the state keeps its prisoner",
+        }
+    }
+}
+
+// Per-match tallies used by the headless balance summary
+#[derive(Resource, Default)]
+struct MatchStats {
+    units_lost: HashMap<UnitType, u32>,
 }
 
 impl Default for GameState {
@@ -180,7 +332,11 @@ impl Default for GameState {
             cartel_score: 0,
             military_score: 0,
             game_phase: GamePhase::Preparation,
-            ovidio_captured: false,
+            ovidio_status: OvidioStatus::Free,
+            outcome: None,
+            civilian_casualties: CivilianCasualties::default(),
+            mission: MissionProgress::default(),
+            scripted_outcome: None,
         }
     }
 }
@@ -190,7 +346,16 @@ impl Default for GameState {
 // ==================== MAIN FUNCTION ====================
 
 fn main() {
-    App::new()
+    let args = cli::CliArgs::parse();
+    let seed = args.seed.unwrap_or_else(rand::random);
+    
+    if args.headless {
+        headless::run(&args, seed);
+        return;
+    }
+    
+    let mut app = App::new();
+    app
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Battle of Culiacán - El Culiacanazo RTS".into(),
@@ -201,26 +366,139 @@ fn main() {
             ..default()
         }))
         .add_plugins(KiraAudioPlugin)
-        .init_resource::<GameState>()
-        .add_systems(Startup, (setup_assets, setup_ui, setup_game).chain())
+        .add_plugins(SimulationPlugin {
+            seed,
+            side: args.side.unwrap_or_default(),
+            scenario: args.scenario.clone(),
+            waves: args.waves.clone(),
+            difficulty: args.difficulty.unwrap_or_default(),
+        })
+        .add_plugins(ScreensPlugin)
+        .insert_resource(SaveRequests {
+            save: None,
+            load: args.load.clone(),
+        })
+        .init_resource::<DragSelection>()
+        .init_resource::<RoadblockPlacement>()
+        .init_resource::<AiDebug>()
+        .add_systems(Startup, (setup_assets, setup_ui).chain().before(setup_game))
+        .add_systems(Startup, placement::spawn_roadblock_ghost)
+        .add_systems(Update, (
+            render_sync_system,
+            facing_sync_system,
+            vehicles::vehicle_render_system.after(facing_sync_system),
+            health_bar_system.after(render_sync_system),
+            ui_update_system,
+            controls_text_system,
+            mission::objective_hud_system,
+            pressure::pressure_hud_system,
+            economy::economy_hud_system,
+            strategic::strategic_point_flag_system,
+            zones::zone_tint_system,
+            behavior::decision_label_system,
+            audio::play_sound_system,
+        ))
         .add_systems(Update, (
-            wave_spawner_system,
-            unit_ai_system,
-            movement_system,
-            combat_system,
-            health_bar_system,
             particle_system,
             damage_indicator_system,
-            game_phase_system,
             handle_input,
-            ui_update_system,
-        ))
-        .run();
+            selection::selection_system,
+            placement::placement_system.after(selection::selection_system),
+            // Runs first so the click that cancels placement is not also a move order
+            selection::order_system.before(placement::placement_system),
+            selection::selection_highlight_system,
+        ).run_if(in_state(AppState::InGame)));
+    
+    #[cfg(debug_assertions)]
+    app.add_systems(Update, unit_stats::unit_stats_hot_reload_system);
+    
+    app.run();
+}
+
+// Everything the match needs to run, shared by the windowed game and headless runs
+struct SimulationPlugin {
+    seed: u64,
+    side: PlayerSide,
+    // Scenario file to play; None plays the historical scenario
+    scenario: Option<String>,
+    // Wave schedule file; None leaves it to the difficulty preset
+    waves: Option<String>,
+    difficulty: Difficulty,
+}
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        let economy = load_economy_rules_or_builtin(CARTEL_ECONOMY_PATH);
+        app
+            .init_resource::<GameState>()
+            .init_resource::<MatchStats>()
+            .init_resource::<PlayerCommands>()
+            .init_resource::<SaveRequests>()
+            .add_event::<SoundEvent>()
+            .add_event::<ShotFired>()
+            .add_state::<AppState>()
+            .insert_resource(SimRng::new(self.seed))
+            .insert_resource(Time::<Fixed>::from_hz(SIM_HZ))
+            .init_resource::<PoliticalPressure>()
+            .init_resource::<Squads>()
+            .init_resource::<CartelCommander>()
+            .insert_resource(self.side)
+            .insert_resource(load_unit_registry_or_builtin(UNIT_STATS_PATH))
+            .insert_resource(behavior::load_behavior_library_or_builtin(behavior::UNIT_BEHAVIORS_PATH))
+            .insert_resource(load_ovidio_metadata_or_builtin(OVIDIO_METADATA_PATH))
+            .insert_resource(CartelResources::new(&economy))
+            .insert_resource(economy)
+            .insert_resource(mission::load_scenario_or_builtin(
+                self.scenario.as_deref().unwrap_or(mission::SCENARIO_PATH),
+            ))
+            .insert_resource(WaveSchedule {
+                custom: self.waves.is_some(),
+                ..waves::load_wave_schedule_or_builtin(self.waves.as_deref().unwrap_or(waves::WAVE_SCHEDULE_PATH))
+            })
+            .insert_resource(self.difficulty)
+            .insert_resource(difficulty::load_difficulty_presets_or_builtin(difficulty::DIFFICULTY_PRESETS_PATH))
+            .add_systems(Startup, setup_game)
+            // The windowed game starts from the briefing, headless runs straight from the menu
+            .add_systems(OnTransition { from: AppState::Briefing, to: AppState::InGame }, difficulty::apply_difficulty_system)
+            .add_systems(OnTransition { from: AppState::MainMenu, to: AppState::InGame }, difficulty::apply_difficulty_system)
+            // Deterministic simulation, one fixed step at a time and in a fixed order
+            .add_systems(FixedUpdate, (
+                (
+                    save::load_match_system,
+                    // The rest of the tick must see the loaded units, not the old ones
+                    apply_deferred,
+                    save::save_match_system,
+                    commander::cartel_commander_system,
+                    player_command_system,
+                    wave_spawner_system,
+                    // Fresh fireteams must reach the squad AI with their members
+                    apply_deferred,
+                    unit_ai_system,
+                    squads::squad_ai_system,
+                    capture::capture_system,
+                    movement_system,
+                    vehicles::vehicle_movement_system,
+                    vehicles::turret_system,
+                    combat_system,
+                ).chain(),
+                (
+                    civilians::stray_fire_system,
+                    civilians::civilian_panic_system,
+                    civilians::civilian_movement_system,
+                    strategic::strategic_point_system,
+                    zones::zone_control_system,
+                    economy::cartel_income_system,
+                    pressure::political_pressure_system,
+                    mission::mission_system,
+                    game_phase_system,
+                ).chain(),
+            ).chain().run_if(in_state(AppState::InGame)));
+    }
 }
 
 // ==================== SETUP SYSTEMS ====================
 
-fn setup_assets(mut commands: Commands, _asset_server: Res<AssetServer>) {
+fn setup_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     // For now, we'll create colored sprites programmatically
     // Later these can be replaced with actual sprite files
     
@@ -237,9 +515,12 @@ fn setup_assets(mut commands: Commands, _asset_server: Res<AssetServer>) {
         health_bar_bg: Handle::default(),
         health_bar_fill: Handle::default(),
         main_font: Handle::default(), // Use default font for now
-        gunshot_sound: Handle::default(),
-        explosion_sound: Handle::default(),
-        radio_chatter: Handle::default(),
+        military_gunfire_sound: load_sound(&asset_server, audio::MILITARY_GUNFIRE_PATH),
+        cartel_gunfire_sound: load_sound(&asset_server, audio::CARTEL_GUNFIRE_PATH),
+        death_sound: load_sound(&asset_server, audio::DEATH_PATH),
+        explosion_sound: load_sound(&asset_server, audio::EXPLOSION_PATH),
+        radio_chatter: load_sound(&asset_server, audio::RADIO_CHATTER_PATH),
+        construction_sound: load_sound(&asset_server, audio::CONSTRUCTION_PATH),
     };
     
     commands.insert_resource(assets);
@@ -323,6 +604,12 @@ fn setup_ui(mut commands: Commands, _asset_server: Res<AssetServer>) {
                         ),
                         ScoreText,
                     ));
+                    
+                    // Cartel Money & Manpower
+                    economy::spawn_economy_hud(parent);
+                    
+                    // Political Pressure Meter
+                    pressure::spawn_pressure_meter(parent);
                 });
             
             // Mission Status (Center)
@@ -352,6 +639,9 @@ fn setup_ui(mut commands: Commands, _asset_server: Res<AssetServer>) {
                     ));
                 });
             
+            // Current phase objectives
+            mission::spawn_objective_panel(parent);
+            
             // Bottom Control Bar
             parent
                 .spawn(NodeBundle {
@@ -369,20 +659,51 @@ fn setup_ui(mut commands: Commands, _asset_server: Res<AssetServer>) {
                     ..default()
                 })
                 .with_children(|parent| {
-                    parent.spawn(TextBundle::from_section(
-                        "🎮 SPACE: Deploy Roadblock | R: Call Reinforcements | ESC: Exit | F1: Help",
-                        TextStyle {
-                            font_size: 16.0,
-                            color: Color::rgb(0.8, 0.8, 0.8),
-                            ..default()
-                        },
+                    parent.spawn((
+                        TextBundle::from_section(
+                            controls_hint(PlayerSide::default()),
+                            TextStyle {
+                                font_size: 16.0,
+                                color: Color::rgb(0.8, 0.8, 0.8),
+                                ..default()
+                            },
+                        ),
+                        ControlsText,
                     ));
                 });
         });
 }
 
-fn setup_game(mut commands: Commands, _assets: Option<Res<GameAssets>>) {
+// Bottom bar text for the side the player picked
+fn controls_hint(side: PlayerSide) -> &'static str {
+    match side {
+        PlayerSide::Cartel => "🎮 LMB: Select | RMB: Move/Attack | SPACE: Roadblock | R: Reinforcements | 1-3: Escape Route | ESC: Pause | F1: Help",
+        PlayerSide::Military => "🎮 LMB: Select soldiers | RMB: Move/Attack | ESC: Pause | F1: Help",
+        PlayerSide::Spectator => "🎮 Watching AI vs AI | F3: AI Decisions | ESC: Pause | F1: Help",
+    }
+}
+
+fn controls_text_system(
+    side: Res<PlayerSide>,
+    mut text_query: Query<&mut Text, With<ControlsText>>,
+) {
+    if !side.is_changed() {
+        return;
+    }
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = controls_hint(*side).to_string();
+    }
+}
+
+fn setup_game(
+    mut commands: Commands,
+    registry: Res<UnitRegistry>,
+    schedule: Res<WaveSchedule>,
+    mut rng: ResMut<SimRng>,
+    _assets: Option<Res<GameAssets>>,
+) {
     info!("🎮 Battle of Culiacán - October 17, 2019");
+    info!("🎲 Simulation seed: {} (replay with --seed {})", rng.seed, rng.seed);
     info!("🏛️  Government forces attempt to capture Ovidio Guzmán López");
     info!("⚔️  Sinaloa Cartel prepares defensive operations");
     
@@ -391,12 +712,15 @@ fn setup_game(mut commands: Commands, _assets: Option<Res<GameAssets>>) {
     info!("🌅 *MORNING SOUNDS* Culiacán awakens to the sound of helicopters...");
     info!("🚁 *DISTANT ROTOR BLADES* Military forces approaching coordinates...");
     
-    // Create visible ground plane
+    let layout = load_map_or_builtin(MAP_LAYOUT_PATH);
+    let grid = NavGrid::from_layout(&layout);
+    
+    // Create visible ground plane covering the projected map bounds
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
                 color: Color::rgb(0.3, 0.4, 0.2), // Darker ground for contrast
-                custom_size: Some(Vec2::new(800.0, 600.0)),
+                custom_size: Some(iso_extent(layout.size())),
                 ..default()
             },
             transform: Transform::from_xyz(0.0, 0.0, -10.0), // No rotation for now
@@ -435,18 +759,119 @@ fn setup_game(mut commands: Commands, _assets: Option<Res<GameAssets>>) {
             Name::new("GridLine"),
         ));
     }
+
+    // Roads, drawn as projected strips; roadblocks snap onto them
+    for (start, end) in layout.road_segments() {
+        let (start, end) = (world_to_iso(start), world_to_iso(end));
+        let direction = end - start;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.35, 0.35, 0.35, 0.8),
+                    custom_size: Some(Vec2::new(direction.length(), 8.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation((start + end) * 0.5 + Vec3::new(0.0, 0.0, -7.5))
+                    .with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x))),
+                ..default()
+            },
+            Name::new("Road"),
+        ));
+    }
+
+    // City zones from the map file
+    let (zones, zone_errors) = layout.zones();
+    for err in zone_errors {
+        error!("❌ {}", err);
+    }
+    civilians::populate_zones(&mut commands, &zones, &grid, &mut rng);
+    for zone in zones {
+        let tint = zones::zone_tint(&zone.initial_control);
+        let iso_center = world_to_iso(zone.center);
+        
+        commands.spawn(Text2dBundle {
+            text: Text::from_section(
+                zone.name.clone(),
+                TextStyle {
+                    font_size: 12.0,
+                    color: Color::rgba(1.0, 1.0, 1.0, 0.7),
+                    ..default()
+                },
+            ),
+            transform: Transform::from_translation(iso_center + Vec3::new(0.0, 0.0, -7.0)),
+            ..default()
+        });
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: tint,
+                    custom_size: Some(iso_extent(zone.size)),
+                    ..default()
+                },
+                transform: Transform::from_translation(iso_center + Vec3::new(0.0, 0.0, -8.0)),
+                ..default()
+            },
+            Name::new(format!("Zone: {}", zone.name)),
+            ZoneControl::new(zone.initial_control.clone()),
+            zone,
+        ));
+    }
+    
+    // Strategic points become capturable checkpoint objectives
+    for point in &layout.strategic_points {
+        let position = layout.to_world(point.position);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.9, 0.9, 0.3, 0.6),
+                    custom_size: Some(Vec2::new(16.0, 16.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(world_to_iso(position) + Vec3::new(0.0, 0.0, -1.0)),
+                ..default()
+            },
+            Objective {
+                objective_type: ObjectiveType::Checkpoint,
+                position,
+                radius: 60.0,
+                health: 100.0,
+            },
+            StrategicPoint::from_data(point),
+            Name::new(point.name.clone()),
+        )).with_children(|parent| {
+            // Flag label, kept up to date with owner and capture progress
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        format!("🚩 {}", point.name),
+                        TextStyle {
+                            font_size: 11.0,
+                            color: Color::rgb(1.0, 1.0, 0.6),
+                            ..default()
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 16.0, 2.0),
+                    ..default()
+                },
+                StrategicPointLabel,
+            ));
+        });
+    }
     
     // Spawn Ovidio (High Value Target) in safehouse
-    spawn_ovidio(&mut commands, Vec3::new(-300.0, 200.0, 0.0));
+    let safehouse_pos = layout.ovidio_position();
+    spawn_ovidio(&mut commands, &registry, safehouse_pos);
     
-    // Spawn initial cartel defenders
-    for i in 0..3 {
-        spawn_unit(&mut commands, UnitType::Sicario, Faction::Cartel, 
-                   Vec3::new(-250.0 + i as f32 * 50.0, 150.0, 0.0));
+    // Spawn initial units from the faction spawn points
+    let (spawns, spawn_errors) = layout.unit_spawns();
+    for err in spawn_errors {
+        error!("❌ {}", err);
+    }
+    for spawn in spawns {
+        spawn_unit(&mut commands, &registry, spawn.unit_type, spawn.faction, spawn.position);
     }
     
     // Spawn safehouse objective with enhanced graphics
-    let safehouse_pos = Vec3::new(-300.0, 200.0, 0.0);
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
@@ -482,157 +907,247 @@ fn setup_game(mut commands: Commands, _assets: Option<Res<GameAssets>>) {
         },
     ));
     
+    // Military extraction point, where detained Ovidio is escorted to
+    if let Some(extraction_pos) = layout.landmark(capture::EXTRACTION_LANDMARK) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.2, 0.5, 0.3, 0.6),
+                    custom_size: Some(Vec2::new(40.0, 40.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(world_to_iso(extraction_pos) + Vec3::new(0.0, 0.0, -1.0)),
+                ..default()
+            },
+            Objective {
+                objective_type: ObjectiveType::ExtractionPoint,
+                position: extraction_pos,
+                radius: 40.0,
+                health: 100.0,
+            },
+        ));
+        
+        commands.spawn(Text2dBundle {
+            text: Text::from_section(
+                "🚁 EXTRACTION",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::rgb(0.6, 1.0, 0.6),
+                    ..default()
+                },
+            ),
+            transform: Transform::from_translation(world_to_iso(extraction_pos) + Vec3::new(0.0, 28.0, 1.0)),
+            ..default()
+        });
+    } else {
+        warn!("🗺️ Map has no '{}' zone, so a detained Ovidio cannot be extracted", capture::EXTRACTION_LANDMARK);
+    }
+    
+    commands.insert_resource(RoadNetwork::from_layout(&layout));
+    commands.insert_resource(grid);
+    commands.insert_resource(layout);
+    
     // Spawn wave spawner
     commands.spawn(WaveSpawner {
-        next_wave_timer: Timer::new(Duration::from_secs(10), TimerMode::Repeating),
+        next_wave_timer: Timer::new(Duration::from_secs_f32(schedule.wave(1).delay), TimerMode::Repeating),
         wave_number: 0,
-        units_in_wave: 2,
     });
     
     info!("🎯 Mission: Defend Ovidio and prevent extraction!");
-    info!("📱 Controls: SPACE=Roadblock, R=Reinforcements, ESC=Exit");
+    info!("📱 Controls: SPACE=Roadblock, R=Reinforcements, ESC=Pause");
 }
 
-fn spawn_ovidio(commands: &mut Commands, position: Vec3) {
+fn spawn_ovidio(commands: &mut Commands, registry: &UnitRegistry, position: Vec3) -> Entity {
+    let stats = registry.get(&UnitType::Ovidio);
     let entity = commands.spawn((
         SpriteBundle {
             sprite: Sprite {
-                color: Color::rgb(1.0, 0.8, 0.0), // Golden color for HVT
-                custom_size: Some(Vec2::new(30.0, 30.0)),
+                color: stats.color(), // Golden color for HVT
+                custom_size: Some(stats.size()),
                 ..default()
             },
             transform: Transform::from_translation(world_to_iso(position)),
             // Remove rotation for visibility
             ..default()
         },
+        WorldPosition(position),
         Unit {
-            health: 50.0,
-            max_health: 50.0,
+            health: stats.health,
+            max_health: stats.health,
             faction: Faction::Cartel,
             unit_type: UnitType::Ovidio,
-            damage: 0.0,
-            range: 0.0,
-            movement_speed: 60.0,
+            damage: stats.damage,
+            range: stats.range,
+            movement_speed: stats.speed,
             target: None,
-            attack_cooldown: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+            attack_cooldown: Timer::new(stats.cooldown(), TimerMode::Repeating),
         },
         Movement {
             target_position: None,
-            speed: 60.0,
+            speed: stats.speed,
         },
-    )).id();
-    
-    // Add crown emoji label for Ovidio
-    commands.spawn(Text2dBundle {
-        text: Text::from_section(
-            "👑",
-            TextStyle {
-                font_size: 20.0,
-                color: Color::YELLOW,
-                ..default()
-            },
-        ),
-        transform: Transform::from_translation(world_to_iso(position) + Vec3::new(0.0, 25.0, 1.0)),
-        ..default()
-    });
+        NavPath::default(),
+    )).with_children(|parent| {
+        // Add crown emoji label for Ovidio
+        parent.spawn(Text2dBundle {
+            text: Text::from_section(
+                stats.label.clone(),
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::YELLOW,
+                    ..default()
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 25.0, 1.0),
+            ..default()
+        });
+    }).id();
     
     // Add health bar for Ovidio
     spawn_health_bar(commands, entity, position);
+    entity
 }
 
-fn spawn_unit(commands: &mut Commands, unit_type: UnitType, faction: Faction, position: Vec3) {
-    // Get unit color, size, health, damage, range, speed based on type and faction
-    let (color, size, _emoji, health, damage, range, speed) = match (&unit_type, &faction) {
-        (UnitType::Sicario, Faction::Cartel) => 
-            (Color::rgb(0.9, 0.2, 0.2), Vec2::new(18.0, 18.0), "🔫", 80.0, 25.0, 120.0, 100.0),
-        (UnitType::Enforcer, Faction::Cartel) => 
-            (Color::rgb(0.7, 0.1, 0.1), Vec2::new(24.0, 24.0), "⚔️", 120.0, 40.0, 150.0, 80.0),
-        (UnitType::Soldier, Faction::Military) => 
-            (Color::rgb(0.2, 0.6, 0.2), Vec2::new(18.0, 18.0), "🪖", 100.0, 30.0, 140.0, 90.0),
-        (UnitType::SpecialForces, Faction::Military) => 
-            (Color::rgb(0.1, 0.8, 0.1), Vec2::new(22.0, 22.0), "🎯", 140.0, 50.0, 180.0, 110.0),
-        (UnitType::Vehicle, Faction::Military) => 
-            (Color::rgb(0.3, 0.7, 0.3), Vec2::new(35.0, 25.0), "🚗", 200.0, 60.0, 200.0, 70.0),
-        (UnitType::Roadblock, Faction::Cartel) => 
-            (Color::rgb(0.7, 0.4, 0.1), Vec2::new(30.0, 15.0), "🚧", 75.0, 0.0, 0.0, 0.0),
-        _ => (Color::GRAY, Vec2::new(18.0, 18.0), "❓", 100.0, 20.0, 100.0, 80.0),
-    };
+fn spawn_unit(commands: &mut Commands, registry: &UnitRegistry, unit_type: UnitType, faction: Faction, position: Vec3) -> Entity {
+    // Color, size, health, damage, range and speed come from the unit stat table
+    let stats = registry.get(&unit_type);
     
     let entity = commands.spawn((
         SpriteBundle {
             sprite: Sprite {
-                color,
-                custom_size: Some(size),
+                color: stats.color(),
+                custom_size: Some(stats.size()),
                 ..default()
             },
             transform: Transform::from_translation(world_to_iso(position)),
             // Remove diamond rotation for better visibility
             ..default()
         },
+        WorldPosition(position),
         Unit {
-            health,
-            max_health: health,
+            health: stats.health,
+            max_health: stats.health,
             faction: faction.clone(),
             unit_type: unit_type.clone(),
-            damage,
-            range,
-            movement_speed: speed,
+            damage: stats.damage,
+            range: stats.range,
+            movement_speed: stats.speed,
             target: None,
-            attack_cooldown: Timer::new(Duration::from_millis(800), TimerMode::Repeating),
+            attack_cooldown: Timer::new(stats.cooldown(), TimerMode::Repeating),
         },
         Movement {
             target_position: None,
-            speed,
+            speed: stats.speed,
         },
-    )).id();
+        NavPath::default(),
+        Decision::default(),
+    )).with_children(|parent| {
+        // Labels ride along as children so they follow the unit and die with it
+        parent.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    stats.label.clone(),
+                    TextStyle {
+                        font_size: 18.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+                transform: Transform::from_xyz(0.0, 20.0, 2.0),
+                ..default()
+            },
+            Name::new("UnitEmoji"),
+        ));
+        
+        parent.spawn(Text2dBundle {
+            text: Text::from_section(
+                "📍", // Generic marker
+                TextStyle {
+                    font_size: 10.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 20.0, 1.0),
+            ..default()
+        });
+        
+        // What the AI is up to, shown with F3
+        parent.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 10.0,
+                        color: Color::rgb(0.6, 0.9, 1.0),
+                        ..default()
+                    },
+                ),
+                transform: Transform::from_xyz(0.0, -20.0, 2.0),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DecisionLabel,
+        ));
+    }).id();
+    
+    // Vehicles get a crew, a heading and a turret
+    if vehicles::is_vehicle(&unit_type) {
+        vehicles::equip(commands, entity, stats);
+    }
     
     // Add health bar for all units except roadblocks
     if unit_type != UnitType::Roadblock {
         spawn_health_bar(commands, entity, position);
     }
-    
-    // Add emoji indicator above the unit for better identification
-    let unit_label = match unit_type {
-        UnitType::Sicario => "🔫",
-        UnitType::Enforcer => "💪", 
-        UnitType::Soldier => "🪖",
-        UnitType::SpecialForces => "⭐",
-        UnitType::Vehicle => "🚗",
-        UnitType::Roadblock => "🚧",
-        _ => "❓",
-    };
-    
+    entity
+}
+
+// Static cartel roadblock; the caller decides whether it blocks the nav grid
+fn spawn_roadblock(commands: &mut Commands, registry: &UnitRegistry, position: Vec3, heading: f32) -> Entity {
+    let stats = registry.get(&UnitType::Roadblock);
     commands.spawn((
-        Text2dBundle {
+        SpriteBundle {
+            sprite: Sprite {
+                color: stats.color(),
+                custom_size: Some(stats.size()),
+                ..default()
+            },
+            transform: Transform::from_translation(world_to_iso(position)).with_rotation(iso_rotation(heading)),
+            ..default()
+        },
+        WorldPosition(position),
+        Facing(heading),
+        Unit {
+            health: stats.health,
+            max_health: stats.health,
+            faction: Faction::Cartel,
+            unit_type: UnitType::Roadblock,
+            damage: stats.damage,
+            range: stats.range,
+            movement_speed: stats.speed,
+            target: None,
+            attack_cooldown: Timer::new(stats.cooldown(), TimerMode::Repeating),
+        },
+    )).with_children(|parent| {
+        // Add roadblock label
+        parent.spawn(Text2dBundle {
             text: Text::from_section(
-                unit_label,
+                format!("{} ROADBLOCK", stats.label),
                 TextStyle {
-                    font_size: 18.0,
-                    color: Color::WHITE,
+                    font_size: 10.0,
+                    color: Color::ORANGE,
                     ..default()
                 },
             ),
-            transform: Transform::from_translation(world_to_iso(position) + Vec3::new(0.0, 20.0, 2.0)),
+            transform: Transform::from_xyz(0.0, 20.0, 1.0),
             ..default()
-        },
-        Name::new("UnitEmoji"),
-    ));
-    
-    commands.spawn(Text2dBundle {
-        text: Text::from_section(
-            "📍", // Generic marker
-            TextStyle {
-                font_size: 10.0,
-                color: Color::WHITE,
-                ..default()
-            },
-        ),
-        transform: Transform::from_translation(position + Vec3::new(0.0, 20.0, 1.0)),
-        ..default()
-    });
+        });
+    }).id()
 }
 
 fn spawn_health_bar(commands: &mut Commands, owner: Entity, position: Vec3) {
+    let iso_position = world_to_iso(position);
     let _health_bg = commands.spawn((
         SpriteBundle {
             sprite: Sprite {
@@ -640,7 +1155,7 @@ fn spawn_health_bar(commands: &mut Commands, owner: Entity, position: Vec3) {
                 custom_size: Some(Vec2::new(30.0, 4.0)),
                 ..default()
             },
-            transform: Transform::from_translation(position + Vec3::new(0.0, -25.0, 1.0)),
+            transform: Transform::from_translation(iso_position + Vec3::new(0.0, -25.0, 1.0)),
             ..default()
         },
         HealthBar {
@@ -656,7 +1171,7 @@ fn spawn_health_bar(commands: &mut Commands, owner: Entity, position: Vec3) {
                 custom_size: Some(Vec2::new(30.0, 4.0)),
                 ..default()
             },
-            transform: Transform::from_translation(position + Vec3::new(0.0, -25.0, 2.0)),
+            transform: Transform::from_translation(iso_position + Vec3::new(0.0, -25.0, 2.0)),
             ..default()
         },
         HealthBar {
@@ -668,12 +1183,28 @@ fn spawn_health_bar(commands: &mut Commands, owner: Entity, position: Vec3) {
 
 // ==================== GAME SYSTEMS ====================
 
+// Everything that decides when a wave comes, what it brings and where from
+#[derive(SystemParam)]
+struct WaveContext<'w, 's> {
+    time: Res<'w, Time>,
+    registry: Res<'w, UnitRegistry>,
+    schedule: Res<'w, WaveSchedule>,
+    preset: Res<'w, DifficultyPreset>,
+    layout: Res<'w, MapLayout>,
+    side: Res<'w, PlayerSide>,
+    point_query: Query<'w, 's, &'static StrategicPoint>,
+}
+
 fn wave_spawner_system(
-    time: Res<Time>,
     mut commands: Commands,
+    context: WaveContext,
+    mut rng: ResMut<SimRng>,
     mut wave_query: Query<&mut WaveSpawner>,
     mut game_state: ResMut<GameState>,
+    mut squads: ResMut<Squads>,
+    mut sounds: EventWriter<SoundEvent>,
 ) {
+    let WaveContext { time, registry, schedule, preset, layout, side, point_query } = context;
     for mut spawner in wave_query.iter_mut() {
         spawner.next_wave_timer.tick(time.delta());
         
@@ -681,182 +1212,328 @@ fn wave_spawner_system(
             spawner.wave_number += 1;
             game_state.current_wave = spawner.wave_number;
             
-            let wave_difficulty = (spawner.wave_number as f32 * 0.5 + 1.0).min(4.0);
-            let units_to_spawn = (spawner.units_in_wave as f32 * wave_difficulty) as u32;
-            
-            info!("🚁 *HELICOPTER ROTORS* 🌊 WAVE {} INCOMING! {} military units deployed 📻 *RADIO STATIC*", spawner.wave_number, units_to_spawn);
+            let wave = schedule.wave(spawner.wave_number);
+            let roster = schedule.roster(spawner.wave_number, preset.wave_size);
+            let entry_points = waves::entry_positions(&layout, wave);
+            let names: Vec<&str> = entry_points.iter().map(|(_, name)| *name).collect();
             
-            // Atmospheric audio cues
-            match spawner.wave_number {
-                1 => info!("📻 'Alpha team, move in! Target: Ovidio Guzmán!'"),
-                2 => info!("📻 'Bravo team, reinforce Alpha! Heavy resistance!'"), 
-                3 => info!("📻 'Charlie team, we need immediate backup!'"),
-                4 => info!("📻 'All units! Full assault! Take the safehouse!'"),
-                _ => info!("📻 'Command, we're escalating operations!'"),
+            info!("🚁 *HELICOPTER ROTORS* 🌊 WAVE {} INCOMING! {} military units deployed 📻 *RADIO STATIC*", spawner.wave_number, roster.len());
+            info!("🧭 Entering from: {}", names.join(", "));
+            sounds.send(SoundEvent::WaveRadio);
+            if let Some(radio) = &wave.radio {
+                info!("📻 '{}'", radio);
+                game_state.mission.show_message(radio);
             }
             
-            // Spawn military units from different entry points
-            let entry_points = vec![
-                Vec3::new(600.0, 300.0, 0.0),   // North entry
-                Vec3::new(600.0, 0.0, 0.0),     // East entry  
-                Vec3::new(600.0, -300.0, 0.0),  // South entry
-            ];
-            
-            for i in 0..units_to_spawn {
-                let entry_point = entry_points[i as usize % entry_points.len()];
+            // Every four soldiers make a fireteam with its own entry point and
+            // objective; a human commander gets them as loose troops instead
+            let fireteams = side.is_ai(&Faction::Military);
+            let mut squad = 0;
+            for (i, unit_type) in roster.into_iter().enumerate() {
+                let i = i as u32;
+                let team = (i / FIRETEAM_SIZE) as usize;
+                let (entry_point, _) = entry_points[team % entry_points.len()];
+                if fireteams && i.is_multiple_of(FIRETEAM_SIZE) {
+                    let objective = match wave.objectives.get(team) {
+                        Some(objective) => waves::resolve_objective(objective, &layout),
+                        None => squads::wave_objective(spawner.wave_number, team, point_query.iter()),
+                    };
+                    squad = squads.raise(objective.clone(), entry_point);
+                    info!("📻 'Fireteam {}, {}!'", squad, objective.describe());
+                }
+                
                 let offset = Vec3::new(
-                    thread_rng().gen_range(-50.0..50.0),
-                    thread_rng().gen_range(-50.0..50.0), 
+                    rng.rng.gen_range(-50.0..50.0),
+                    rng.rng.gen_range(-50.0..50.0), 
                     0.0
                 );
                 
-                let unit_type = match spawner.wave_number {
-                    1..=2 => UnitType::Soldier,
-                    3..=4 => if thread_rng().gen_bool(0.7) { UnitType::Soldier } else { UnitType::SpecialForces },
-                    _ => if thread_rng().gen_bool(0.4) { UnitType::Vehicle } else { UnitType::SpecialForces },
-                };
-                
-                spawn_unit(&mut commands, unit_type, Faction::Military, entry_point + offset);
+                let soldier = spawn_unit(&mut commands, &registry, unit_type, Faction::Military, entry_point + offset);
+                if fireteams {
+                    commands.entity(soldier).insert(SquadMember {
+                        squad,
+                        slot: i % FIRETEAM_SIZE,
+                        role: default(),
+                    });
+                }
             }
             
-            // Increase difficulty for next wave
-            spawner.units_in_wave = (spawner.units_in_wave as f32 * 1.2) as u32;
+            // The schedule sets the gap before the next wave
+            let delay = schedule.wave(spawner.wave_number + 1).delay * preset.wave_delay;
+            spawner.next_wave_timer.set_duration(Duration::from_secs_f32(delay));
         }
     }
 }
 
+// Nearest unit of `enemy` faction within `range` of `position`
+fn nearest_enemy_in_range(
+    units: &[(Entity, Faction, Vec3)],
+    position: Vec3,
+    range: f32,
+    enemy: &Faction,
+) -> Option<Entity> {
+    units.iter()
+        .filter(|(_, faction, _)| faction == enemy)
+        .map(|(entity, _, other)| (*entity, position.distance(*other)))
+        .filter(|(_, distance)| *distance <= range)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+// Cartel units this close to a fleeing Ovidio go with him
+const ESCAPE_ESCORT_RADIUS: f32 = 150.0;
+
+type Thinker = (
+    Entity,
+    &'static mut Unit,
+    &'static WorldPosition,
+    Option<&'static mut Movement>,
+    Option<&'static mut Decision>,
+    Option<&'static SquadMember>,
+);
+
 fn unit_ai_system(
-    mut unit_query: Query<(&mut Unit, &Transform, &mut Movement), Without<Objective>>,
-    objective_query: Query<(&Objective, &Transform), (With<Objective>, Without<Unit>)>,
-    enemy_units: Query<(Entity, &Unit, &Transform), (With<Unit>, Without<Movement>)>,
+    game_state: Res<GameState>,
+    side: Res<PlayerSide>,
+    preset: Res<DifficultyPreset>,
+    library: Res<BehaviorLibrary>,
+    mut unit_query: Query<Thinker>,
+    objective_query: Query<&Objective>,
 ) {
-    for (mut unit, transform, mut movement) in unit_query.iter_mut() {
-        match unit.faction {
-            Faction::Military => {
-                // Military AI: Find and attack cartel units or move to objectives
+    // Soldiers are ordered to take Ovidio alive and only fire on him when he runs
+    let ovidio_targetable = matches!(game_state.ovidio_status, OvidioStatus::Escaping { .. });
+    
+    // Snapshot every unit so the AI sees mobile enemies as well as roadblocks
+    let units: Vec<(Entity, Faction, Vec3)> = unit_query.iter()
+        .filter(|(_, u, ..)| u.unit_type != UnitType::Ovidio || ovidio_targetable)
+        .map(|(e, u, p, ..)| (e, u.faction.clone(), p.0))
+        .collect();
+    let ovidio = unit_query.iter()
+        .find(|(_, u, ..)| u.unit_type == UnitType::Ovidio)
+        .map(|(_, _, p, ..)| p.0);
+    let safehouse = objective_query.iter()
+        .find(|objective| objective.objective_type == ObjectiveType::Safehouse)
+        .map(|objective| objective.position);
+    
+    for (_entity, mut unit, position, movement, decision, member) in unit_query.iter_mut() {
+        // Roadblocks never act and fireteams take their orders from the squad AI
+        let Some(mut movement) = movement else {
+            continue;
+        };
+        if member.is_some() {
+            continue;
+        }
+        
+        let enemy = match unit.faction {
+            Faction::Military => Some(Faction::Cartel),
+            Faction::Cartel => Some(Faction::Military),
+            Faction::Civilian => None,
+        };
+        let nearest_enemy = enemy.and_then(|enemy| nearest_enemy_in_range(&units, position.0, unit.range, &enemy));
+        let fleeing_ovidio = ovidio.filter(|ovidio| ovidio_targetable && position.0.distance(*ovidio) <= ESCAPE_ESCORT_RADIUS);
+        let situation = Situation {
+            has_move_order: movement.target_position.is_some(),
+            has_target: unit.target.is_some(),
+            enemy_in_range: nearest_enemy.is_some(),
+            ovidio_fleeing_nearby: fleeing_ovidio.is_some(),
+            panicked: false,
+            health: unit.health / unit.max_health,
+            ai_controlled: side.is_ai(&unit.faction),
+        };
+        
+        // Ovidio and anything else without behaviors goes where it is taken
+        let Some(chosen) = behavior::choose(library.for_unit(&unit.unit_type), &situation) else {
+            continue;
+        };
+        if let Some(mut decision) = decision {
+            *decision = chosen;
+        }
+        
+        match chosen.action {
+            Action::Engage => {
                 if unit.target.is_none() {
-                    // Find nearest enemy or objective
-                    let mut nearest_target = None;
-                    let mut nearest_distance = f32::MAX;
-                    
-                    // Check for enemy units in range
-                    for (entity, other_unit, other_transform) in enemy_units.iter() {
-                        if other_unit.faction == Faction::Cartel {
-                            let distance = transform.translation.distance(other_transform.translation);
-                            if distance < nearest_distance && distance <= unit.range {
-                                nearest_distance = distance;
-                                nearest_target = Some(entity);
-                            }
-                        }
-                    }
-                    
-                    unit.target = nearest_target;
-                    
-                    // If no target, move toward safehouse
-                    if unit.target.is_none() {
-                        for (objective, obj_transform) in objective_query.iter() {
-                            if objective.objective_type == ObjectiveType::Safehouse {
-                                movement.target_position = Some(obj_transform.translation);
-                                break;
-                            }
+                    // The AI takes a moment to react to a new target
+                    match nearest_enemy {
+                        Some(enemy) if situation.ai_controlled => {
+                            difficulty::acquire_target(&mut unit, enemy, preset.ai_reaction);
                         }
+                        enemy => unit.target = enemy,
                     }
                 }
-            },
-            Faction::Cartel => {
-                // Cartel AI: Defend position and attack nearby military units
-                if unit.target.is_none() {
-                    let mut nearest_enemy = None;
-                    let mut nearest_distance = f32::MAX;
-                    
-                    for (entity, other_unit, other_transform) in enemy_units.iter() {
-                        if other_unit.faction == Faction::Military {
-                            let distance = transform.translation.distance(other_transform.translation);
-                            if distance < nearest_distance && distance <= unit.range {
-                                nearest_distance = distance;
-                                nearest_enemy = Some(entity);
-                            }
-                        }
-                    }
-                    
-                    unit.target = nearest_enemy;
+            }
+            Action::Advance => {
+                // Close in on Ovidio (or escort him), else the safehouse
+                if let Some(goal) = ovidio.or(safehouse) {
+                    movement.target_position = Some(goal);
                 }
-            },
-            _ => {}
+            }
+            Action::EscortOvidio => {
+                // Idle gunmen nearby ride along when Ovidio makes a run for it
+                if let Some(ovidio) = fleeing_ovidio {
+                    movement.target_position = Some(ovidio);
+                }
+            }
+            // Civilian actions are carried out by the civilian movement system
+            Action::Hold | Action::FollowOrders | Action::Flee | Action::Wander => {}
         }
     }
 }
 
+// Distance at which a move order counts as done
+const ARRIVAL_RADIUS: f32 = 8.0;
+
+// Units that never move: roadblocks
+type Stationary = (With<Unit>, Without<Movement>);
+
 fn movement_system(
     time: Res<Time>,
-    mut unit_query: Query<(&mut Transform, &Movement, &Unit)>,
-    target_query: Query<&Transform, (With<Unit>, Without<Movement>)>,
+    grid: Res<NavGrid>,
+    mut unit_query: Query<(Entity, &mut WorldPosition, &mut Movement, &mut NavPath, &Unit), Without<Vehicle>>,
+    static_query: Query<(Entity, &WorldPosition), Stationary>,
+    vehicle_query: Query<(Entity, &WorldPosition), With<Vehicle>>,
 ) {
-    for (mut transform, movement, unit) in unit_query.iter_mut() {
-        if let Some(target_pos) = movement.target_position {
-            let direction = (target_pos - transform.translation).normalize();
-            let movement_delta = direction * movement.speed * time.delta_seconds();
-            
-            // Stop when close enough to target
-            if transform.translation.distance(target_pos) > 20.0 {
-                transform.translation += movement_delta;
-            }
-        }
-        
+    // Snapshot target positions so units can chase moving targets as well as roadblocks;
+    // vehicles drive themselves in vehicles.rs
+    let positions: HashMap<Entity, Vec3> = unit_query.iter()
+        .map(|(e, p, _, _, _)| (e, p.0))
+        .chain(static_query.iter().map(|(e, p)| (e, p.0)))
+        .chain(vehicle_query.iter().map(|(e, p)| (e, p.0)))
+        .collect();
+    
+    for (_entity, mut position, mut movement, mut path, unit) in unit_query.iter_mut() {
         // Move toward target unit if attacking
         if let Some(target_entity) = unit.target {
-            if let Ok(target_transform) = target_query.get(target_entity) {
-                let direction = (target_transform.translation - transform.translation).normalize();
-                let distance = transform.translation.distance(target_transform.translation);
+            if let Some(&target_position) = positions.get(&target_entity) {
+                let distance = position.0.distance(target_position);
                 
                 // Move closer if out of range
                 if distance > unit.range * 0.8 {
-                    let movement_delta = direction * unit.movement_speed * time.delta_seconds();
-                    transform.translation += movement_delta;
+                    let step = unit.movement_speed * time.delta_seconds();
+                    follow_path(&grid, &mut position.0, &mut path, target_position, step);
                 }
+                continue;
+            }
+        }
+        
+        if let Some(target_pos) = movement.target_position {
+            // Stop when close enough to target; tight enough for soldiers to reach capture range
+            if position.0.distance(target_pos) > ARRIVAL_RADIUS {
+                let step = movement.speed * time.delta_seconds();
+                follow_path(&grid, &mut position.0, &mut path, target_pos, step);
+            } else {
+                movement.target_position = None;
+                path.waypoints.clear();
+                path.goal = None;
             }
         }
     }
 }
 
+// Advances a unit along its nav path toward `goal`, replanning when the
+// goal moves a cell or the grid changes
+fn follow_path(grid: &NavGrid, position: &mut Vec3, path: &mut NavPath, goal: Vec3, mut step: f32) {
+    let stale_goal = path.goal.is_none_or(|g| g.distance(goal) > nav::NAV_CELL_SIZE);
+    if stale_goal || path.grid_version != grid.version {
+        // An unreachable goal leaves the path empty, so the unit holds position
+        path.waypoints = grid.find_path(*position, goal).unwrap_or_default();
+        path.goal = Some(goal);
+        path.grid_version = grid.version;
+    }
+    
+    while step > 0.0 && !path.waypoints.is_empty() {
+        let waypoint = path.waypoints[0].truncate().extend(position.z);
+        let distance = position.distance(waypoint);
+        if distance <= step {
+            *position = waypoint;
+            path.waypoints.remove(0);
+            step -= distance;
+        } else {
+            *position += (waypoint - *position) / distance * step;
+            step = 0.0;
+        }
+    }
+}
+
+type Combatant = (
+    Entity,
+    &'static mut Unit,
+    &'static WorldPosition,
+    Option<&'static AttackOrder>,
+    Option<&'static SquadMember>,
+    Option<&'static Vehicle>,
+);
+
+// What a round of fire changes besides the units themselves
+#[derive(SystemParam)]
+struct CombatEffects<'w> {
+    game_state: ResMut<'w, GameState>,
+    grid: ResMut<'w, NavGrid>,
+    stats: ResMut<'w, MatchStats>,
+    sounds: EventWriter<'w, SoundEvent>,
+    shots: EventWriter<'w, ShotFired>,
+}
+
 fn combat_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut unit_query: Query<(Entity, &mut Unit, &Transform)>,
-    mut game_state: ResMut<GameState>,
-    _audio: Res<KiraAudio>, // Add audio system
+    mut unit_query: Query<Combatant>,
+    point_query: Query<(&Objective, &StrategicPoint)>,
+    mut rng: ResMut<SimRng>,
+    effects: CombatEffects,
 ) {
+    let CombatEffects { mut game_state, mut grid, mut stats, mut sounds, mut shots } = effects;
     let mut combat_events = Vec::new();
     
     // Collect combat events first
-    let units: Vec<(Entity, Unit, Transform)> = unit_query.iter()
-        .map(|(e, u, t)| (e, u.clone(), *t))
+    let units: Vec<(Entity, Unit, WorldPosition)> = unit_query.iter()
+        .map(|(e, u, p, ..)| (e, u.clone(), *p))
         .collect();
+    // Who is being kept down by suppressing fire, and by whom
+    let pinned = squads::pinned_targets(&unit_query.iter().map(|(e, u, p, _, m, _)| (e, u, p.0, m)).collect::<Vec<_>>());
     
-    for (_entity, mut unit, _transform) in unit_query.iter_mut() {
+    for (entity, mut unit, position, attack_order, _, vehicle) in unit_query.iter_mut() {
         unit.attack_cooldown.tick(time.delta());
         
         if let Some(target_entity) = unit.target {
             if unit.attack_cooldown.just_finished() {
                 // Check if target still exists in our collected units
-                if let Some((_, _, target_transform)) = units.iter()
+                if let Some((_, _, target_position)) = units.iter()
                     .find(|(e, _, _)| *e == target_entity) {
-                    let distance = _transform.translation.distance(target_transform.translation);
+                    let distance = position.0.distance(target_position.0);
                     if distance <= unit.range {
-                        combat_events.push((target_entity, unit.damage, unit.faction.clone(), _transform.translation, target_transform.translation));
-                    } else {
+                        // A mounted gun waits until the turret is laid on the target
+                        if vehicle.is_some_and(|vehicle| !vehicle.on_target) {
+                            continue;
+                        }
+                        let mut damage = unit.damage;
+                        match pinned.get(&target_entity) {
+                            // Caught in the open by a second gun
+                            Some(&pinner) if pinner != entity => damage *= squads::FLANK_DAMAGE,
+                            _ => {}
+                        }
+                        if pinned.contains_key(&entity) {
+                            damage *= squads::SUPPRESSED_DAMAGE;
+                        }
+                        combat_events.push((target_entity, damage, unit.faction.clone(), position.0, target_position.0));
+                    } else if attack_order.is_none() {
                         unit.target = None; // Target out of range
                     }
                 } else {
                     unit.target = None; // Target no longer exists
+                    if attack_order.is_some() {
+                        commands.entity(entity).remove::<AttackOrder>();
+                    }
                 }
             }
         }
     }
     
     // Apply damage and visual/audio effects
+    let mut killed = Vec::new();
     for (target_entity, damage, attacker_faction, attacker_pos, target_pos) in combat_events {
-        if let Ok((entity, mut unit, transform)) = unit_query.get_mut(target_entity) {
+        if let Ok((entity, mut unit, ..)) = unit_query.get_mut(target_entity) {
+            // Defenders of their own strategic point shrug off part of the fire
+            let damage = damage * strategic::defensive_multiplier(point_query.iter(), &unit.faction, target_pos);
             unit.health -= damage;
             
             // Audio feedback via console - simulating gunshot sounds
@@ -873,14 +1550,19 @@ fn combat_system(
                   damage,
                   unit.health);
             
-            // TODO: Replace with actual audio when files are available
-            // audio.play(assets.gunshot_sound.clone());
+            sounds.send(SoundEvent::Gunfire { faction: attacker_faction.clone() });
+            shots.send(ShotFired {
+                faction: attacker_faction.clone(),
+                from: attacker_pos,
+                to: target_pos,
+                damage,
+            });
             
             // Spawn muzzle flash particles with color effects
             for _ in 0..3 {
                 let velocity = Vec3::new(
-                    thread_rng().gen_range(-100.0..100.0),
-                    thread_rng().gen_range(-100.0..100.0),
+                    rng.rng.gen_range(-100.0..100.0),
+                    rng.rng.gen_range(-100.0..100.0),
                     0.0,
                 );
                 
@@ -918,7 +1600,7 @@ fn combat_system(
                             ..default()
                         },
                     ),
-                    transform: Transform::from_translation(world_to_iso(transform.translation + Vec3::new(0.0, 10.0, 4.0))),
+                    transform: Transform::from_translation(world_to_iso(target_pos) + Vec3::new(0.0, 10.0, 4.0)),
                     ..default()
                 },
                 DamageIndicator {
@@ -929,8 +1611,8 @@ fn combat_system(
             // Impact particles at target
             for _ in 0..2 {
                 let velocity = Vec3::new(
-                    thread_rng().gen_range(-50.0..50.0),
-                    thread_rng().gen_range(-50.0..50.0),
+                    rng.rng.gen_range(-50.0..50.0),
+                    rng.rng.gen_range(-50.0..50.0),
                     0.0,
                 );
                 
@@ -941,7 +1623,7 @@ fn combat_system(
                             custom_size: Some(Vec2::new(2.0, 2.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(target_pos + Vec3::new(0.0, 0.0, 3.0)),
+                        transform: Transform::from_translation(world_to_iso(target_pos) + Vec3::new(0.0, 0.0, 3.0)),
                         ..default()
                     },
                     ParticleEffect {
@@ -966,17 +1648,19 @@ fn combat_system(
                     UnitType::Soldier => "🪖 Soldier",
                     UnitType::SpecialForces => "🎯 Special Forces",
                     UnitType::Vehicle => "🚗 Vehicle",
+                    UnitType::NarcoTechTruck => "🛻 Narco Tech Truck",
                     UnitType::Roadblock => "🚧 Roadblock",
                 };
                 
                 info!("{} {} eliminated! 💥💥💥", death_sound, unit_name);
+                sounds.send(SoundEvent::UnitKilled { unit_type: unit.unit_type.clone() });
+                *stats.units_lost.entry(unit.unit_type.clone()).or_default() += 1;
                 
                 match unit.faction {
                     Faction::Cartel => {
                         game_state.military_score += 10;
                         if unit.unit_type == UnitType::Ovidio {
-                            game_state.ovidio_captured = true;
-                            info!("🎯 CRITICAL: Ovidio Guzmán López captured!");
+                            info!("💀 CRITICAL: Ovidio Guzmán López killed in the crossfire!");
                         }
                     },
                     Faction::Military => {
@@ -985,11 +1669,16 @@ fn combat_system(
                     _ => {}
                 }
                 
+                // A destroyed roadblock reopens the road
+                if unit.unit_type == UnitType::Roadblock {
+                    grid.remove_blocker(target_pos, ROADBLOCK_BLOCK_RADIUS);
+                }
+                
                 // Spawn death explosion effect
                 for _ in 0..8 {
                     let velocity = Vec3::new(
-                        thread_rng().gen_range(-150.0..150.0),
-                        thread_rng().gen_range(-150.0..150.0),
+                        rng.rng.gen_range(-150.0..150.0),
+                        rng.rng.gen_range(-150.0..150.0),
                         0.0,
                     );
                     
@@ -1000,7 +1689,7 @@ fn combat_system(
                                 custom_size: Some(Vec2::new(4.0, 4.0)),
                                 ..default()
                             },
-                            transform: Transform::from_translation(transform.translation + Vec3::new(0.0, 0.0, 3.0)),
+                            transform: Transform::from_translation(world_to_iso(target_pos) + Vec3::new(0.0, 0.0, 3.0)),
                             ..default()
                         },
                         ParticleEffect {
@@ -1011,17 +1700,36 @@ fn combat_system(
                 }
                 
                 commands.entity(entity).despawn_recursive();
+                killed.push(entity);
+            }
+        }
+    }
+    
+    // Nobody keeps aiming at the dead: a save cannot name them, so a loaded
+    // match must see the same empty targets as the one that was saved
+    for (entity, mut unit, _, attack_order, ..) in unit_query.iter_mut() {
+        if unit.target.is_some_and(|target| killed.contains(&target)) {
+            unit.target = None;
+            if attack_order.is_some() {
+                commands.entity(entity).remove::<AttackOrder>();
             }
         }
     }
 }
 
+// Each HUD line on its own, so the text queries never overlap
+type WaveTextOnly = (With<WaveText>, Without<StatusText>, Without<ScoreText>);
+type StatusTextOnly = (With<StatusText>, Without<WaveText>, Without<ScoreText>);
+type ScoreTextOnly = (With<ScoreText>, Without<WaveText>, Without<StatusText>);
+
 fn ui_update_system(
     game_state: Res<GameState>,
+    metadata: Res<OvidioMetadata>,
+    script: Res<MissionScript>,
     unit_query: Query<&Unit>,
-    mut wave_text: Query<&mut Text, (With<WaveText>, Without<StatusText>, Without<ScoreText>)>,
-    mut status_text: Query<&mut Text, (With<StatusText>, Without<WaveText>, Without<ScoreText>)>,
-    mut score_text: Query<&mut Text, (With<ScoreText>, Without<WaveText>, Without<StatusText>)>,
+    mut wave_text: Query<&mut Text, WaveTextOnly>,
+    mut status_text: Query<&mut Text, StatusTextOnly>,
+    mut score_text: Query<&mut Text, ScoreTextOnly>,
 ) {
     // Update wave counter
     if let Ok(mut text) = wave_text.get_single_mut() {
@@ -1044,50 +1752,61 @@ fn ui_update_system(
             .count();
         let ovidio_alive = unit_query.iter()
             .any(|u| u.unit_type == UnitType::Ovidio);
+        let ovidio_label = if ovidio_alive { game_state.ovidio_status.label(&metadata) } else { "KILLED".to_string() };
         
-        let status_msg = match game_state.game_phase {
-            GamePhase::Preparation => "🎯 PREPARING: Government forces mobilizing...",
-            GamePhase::InitialRaid => "🚁 PHASE 1: Initial raid in progress!",
-            GamePhase::BlockConvoy => "🛑 PHASE 2: Block all escape routes!",
-            GamePhase::ApplyPressure => "👥 PHASE 3: Pressure tactics engaged!",
-            GamePhase::HoldTheLine => "⏰ PHASE 4: Final showdown - Hold the line!",
-            GamePhase::GameOver => if game_state.ovidio_captured { "💀 DEFEAT: Ovidio captured" } else { "🏆 VICTORY: Historical outcome achieved" },
-        };
+        let status_msg = script.phase(&game_state.game_phase).map(|phase| phase.title.as_str()).unwrap_or("🎯 MISSION");
         
-        text.sections[0].value = format!("{} | Cartel: {} | Military: {} | Ovidio: {} | Time: {:.0}s",
+        text.sections[0].value = format!("{} | Cartel: {} | Military: {} | Ovidio: {} | Civilians lost: {} | Time: {:.0}s",
                                         status_msg, cartel_count, military_count,
-                                        if ovidio_alive { "SAFE" } else { "CAPTURED" },
+                                        ovidio_label,
+                                        game_state.civilian_casualties.total(),
                                         game_state.mission_timer);
     }
 }
 
+// Turns sprites to match their map heading
+fn facing_sync_system(mut facing_query: Query<(&Facing, &mut Transform), Changed<Facing>>) {
+    for (facing, mut transform) in facing_query.iter_mut() {
+        transform.rotation = iso_rotation(facing.0);
+    }
+}
+
+// Projects every unit's simulation position onto its sprite
+fn render_sync_system(mut unit_query: Query<(&WorldPosition, &mut Transform), Changed<WorldPosition>>) {
+    for (position, mut transform) in unit_query.iter_mut() {
+        transform.translation = world_to_iso(position.0);
+    }
+}
+
 fn health_bar_system(
-    unit_query: Query<(Entity, &Unit, &Transform), Changed<Unit>>,
-    mut health_bar_query: Query<(&mut Transform, &mut Sprite, &HealthBar), Without<Unit>>,
+    mut commands: Commands,
+    unit_query: Query<(&Unit, &Transform)>,
+    mut health_bar_query: Query<(Entity, &mut Transform, &mut Sprite, &HealthBar), Without<Unit>>,
 ) {
-    for (unit_entity, unit, unit_transform) in unit_query.iter() {
-        // Update health bars for this unit
-        for (mut bar_transform, mut bar_sprite, health_bar) in health_bar_query.iter_mut() {
-            if health_bar.owner == unit_entity {
-                // Update position with isometric offset
-                let iso_pos = unit_transform.translation + health_bar.offset;
-                bar_transform.translation = Vec3::new(iso_pos.x, iso_pos.y + 15.0, iso_pos.z); // Higher up in isometric view
-                
-                // Update health bar fill (green bar on top)
-                if health_bar.offset.z > 1.5 { // This is the fill bar
-                    let health_percentage = (unit.health / unit.max_health).max(0.0);
-                    bar_sprite.custom_size = Some(Vec2::new(30.0 * health_percentage, 4.0));
-                    
-                    // Change color based on health
-                    bar_sprite.color = if health_percentage > 0.6 {
-                        Color::rgb(0.2, 0.8, 0.2) // Green
-                    } else if health_percentage > 0.3 {
-                        Color::rgb(0.8, 0.8, 0.2) // Yellow
-                    } else {
-                        Color::rgb(0.8, 0.2, 0.2) // Red
-                    };
-                }
-            }
+    for (bar_entity, mut bar_transform, mut bar_sprite, health_bar) in health_bar_query.iter_mut() {
+        // Drop bars whose unit has been destroyed
+        let Ok((unit, unit_transform)) = unit_query.get(health_bar.owner) else {
+            commands.entity(bar_entity).despawn();
+            continue;
+        };
+        
+        // Update position with isometric offset
+        let iso_pos = unit_transform.translation + health_bar.offset;
+        bar_transform.translation = Vec3::new(iso_pos.x, iso_pos.y + 15.0, iso_pos.z); // Higher up in isometric view
+        
+        // Update health bar fill (green bar on top)
+        if health_bar.offset.z > 1.5 { // This is the fill bar
+            let health_percentage = (unit.health / unit.max_health).max(0.0);
+            bar_sprite.custom_size = Some(Vec2::new(30.0 * health_percentage, 4.0));
+            
+            // Change color based on health
+            bar_sprite.color = if health_percentage > 0.6 {
+                Color::rgb(0.2, 0.8, 0.2) // Green
+            } else if health_percentage > 0.3 {
+                Color::rgb(0.8, 0.8, 0.2) // Yellow
+            } else {
+                Color::rgb(0.8, 0.2, 0.2) // Red
+            };
         }
     }
 }
@@ -1133,188 +1852,239 @@ fn damage_indicator_system(
 fn game_phase_system(
     time: Res<Time>,
     mut game_state: ResMut<GameState>,
+    metadata: Res<OvidioMetadata>,
+    pressure: Res<PoliticalPressure>,
     unit_query: Query<&Unit>,
 ) {
+    if game_state.outcome.is_some() {
+        return;
+    }
+    
     game_state.mission_timer += time.delta_seconds();
     
-    let cartel_alive = unit_query.iter().any(|u| u.faction == Faction::Cartel && u.unit_type != UnitType::Roadblock);
-    let _military_alive = unit_query.iter().any(|u| u.faction == Faction::Military);
     let ovidio_alive = unit_query.iter().any(|u| u.unit_type == UnitType::Ovidio && u.health > 0.0);
     
-    // Phase transitions based on time and events
-    let new_phase = match game_state.game_phase {
-        GamePhase::Preparation if game_state.mission_timer > 5.0 => {
-            info!("🚁 *HELICOPTER SOUNDS* 📻 'ATENCIÓN! OPERATION BLACK THURSDAY INITIATED!' 🚁 Phase 1: INITIAL RAID - Government forces storm the safehouse! 🔊 *SIRENS WAILING*");
-            GamePhase::InitialRaid
-        },
-        GamePhase::InitialRaid if game_state.mission_timer > 120.0 => {
-            info!("🛑 Phase 2: BLOCK CONVOY - Cut off all escape routes!");
-            GamePhase::BlockConvoy  
-        },
-        GamePhase::BlockConvoy if game_state.mission_timer > 300.0 => {
-            info!("👨‍👩‍👧‍👦 Phase 3: APPLY PRESSURE - Target military families!");
-            GamePhase::ApplyPressure
-        },
-        GamePhase::ApplyPressure if game_state.mission_timer > 480.0 => {
-            info!("⏰ Phase 4: HOLD THE LINE - Final showdown!");
-            GamePhase::HoldTheLine
-        },
-        _ => game_state.game_phase.clone(),
-    };
-    
-    if new_phase != game_state.game_phase {
-        game_state.game_phase = new_phase;
-    }
+    // Phases advance through the mission script; this only decides the match
     
     // Victory/Defeat conditions
-    if !ovidio_alive && !game_state.ovidio_captured {
-        info!("💀 DEFEAT: Ovidio Guzmán López was killed in the operation");
-        game_state.game_phase = GamePhase::GameOver;
-    } else if game_state.ovidio_captured && !cartel_alive {
-        info!("🎖️ MILITARY VICTORY: Target captured, cartel eliminated");  
-        game_state.game_phase = GamePhase::GameOver;
-    } else if game_state.mission_timer > 600.0 && cartel_alive {
-        info!("🏆 HISTORICAL OUTCOME: Government releases Ovidio to prevent casualties");
-        info!("📰 'El Culiacanazo' - Cartel demonstrates power over the state");
-        game_state.game_phase = GamePhase::GameOver;
+    let decided = if !ovidio_alive {
+        Some(MatchOutcome::OvidioKilled)
+    } else if game_state.ovidio_status == OvidioStatus::Extracted {
+        // Custody alone decides nothing: the cartel has until extraction to win him back
+        Some(MatchOutcome::MilitaryVictory)
+    } else if matches!(game_state.ovidio_status, OvidioStatus::Escaped { .. }) {
+        Some(MatchOutcome::OvidioEscaped)
+    } else if pressure.government_breaks(&metadata) {
+        // Held or still free, the government lets him go once the cost is too high
+        Some(MatchOutcome::HistoricalRelease)
+    } else {
+        None
+    };
+    let outcome = decided.or(game_state.scripted_outcome);
+    
+    // Ending the match freezes the simulation; the results screen takes over
+    if let Some(outcome) = outcome {
+        info!("{}", outcome.headline());
+        info!("{}", outcome.epilogue());
+        game_state.outcome = Some(outcome);
     }
 }
 
-fn handle_input(
-    input: Res<Input<KeyCode>>,
+// What player commands are checked against
+#[derive(SystemParam)]
+struct CommandContext<'w, 's> {
+    registry: Res<'w, UnitRegistry>,
+    metadata: Res<'w, OvidioMetadata>,
+    layout: Res<'w, MapLayout>,
+    rules: Res<'w, EconomyRules>,
+    zone_query: Query<'w, 's, (&'static Zone, &'static ZoneControl)>,
+}
+
+// What carrying out a command changes besides the units it names
+#[derive(SystemParam)]
+struct CommandEffects<'w> {
+    grid: ResMut<'w, NavGrid>,
+    game_state: ResMut<'w, GameState>,
+    resources: ResMut<'w, CartelResources>,
+    sounds: EventWriter<'w, SoundEvent>,
+}
+
+// Applies queued player commands at the start of a fixed tick
+fn player_command_system(
     mut commands: Commands,
-    mut game_state: ResMut<GameState>,
+    context: CommandContext,
+    effects: CommandEffects,
+    mut rng: ResMut<SimRng>,
+    mut player_commands: ResMut<PlayerCommands>,
+    mut unit_query: Query<(&mut Unit, &mut Movement)>,
 ) {
-    if input.just_pressed(KeyCode::Space) {
-        // Deploy roadblock with enhanced visuals
-        let position = Vec3::new(
-            thread_rng().gen_range(-400.0..400.0),
-            thread_rng().gen_range(-300.0..300.0),
-            0.0
-        );
-        
-        let _entity = commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: Color::rgb(0.7, 0.4, 0.1),
-                    custom_size: Some(Vec2::new(80.0, 30.0)),
-                    ..default()
-                },
-                transform: Transform::from_translation(world_to_iso(position)),
-                // Remove rotation for clarity
-                ..default()
+    let CommandContext { registry, metadata, layout, rules, zone_query } = context;
+    let CommandEffects { mut grid, mut game_state, mut resources, mut sounds } = effects;
+    for command in std::mem::take(&mut player_commands.queue) {
+        match command {
+            PlayerCommand::DeployRoadblock { position, heading } => {
+                // The ground may have changed since the ghost was placed
+                if !grid.contains(position) || grid.is_blocked(grid.world_to_cell(position)) {
+                    warn!("🚧 Cannot build a roadblock there any more");
+                    continue;
+                }
+                if let Err(denied) = resources.try_spend(&rules, CartelAction::Roadblock) {
+                    warn!("🚧 Cannot build a roadblock: {}", denied);
+                    continue;
+                }
+                
+                spawn_roadblock(&mut commands, &registry, position, heading);
+                
+                grid.add_blocker(position, ROADBLOCK_BLOCK_RADIUS);
+                
+                // Spawn construction particles
+                for _ in 0..5 {
+                    let velocity = Vec3::new(
+                        rng.rng.gen_range(-80.0..80.0),
+                        rng.rng.gen_range(-80.0..80.0),
+                        0.0,
+                    );
+                    
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::rgb(0.8, 0.6, 0.2),
+                                custom_size: Some(Vec2::new(3.0, 3.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(world_to_iso(position) + Vec3::new(0.0, 0.0, 2.0)),
+                            ..default()
+                        },
+                        ParticleEffect {
+                            lifetime: Timer::new(Duration::from_millis(400), TimerMode::Once),
+                            velocity,
+                        },
+                    ));
+                }
+                
+                info!("� *CONSTRUCTION SOUNDS* �🛑 ROADBLOCK deployed! Military convoy movement disrupted 📻 'Cartel blocking the roads!'");
+                sounds.send(SoundEvent::RoadblockBuilt);
             },
-            Unit {
-                health: 75.0,
-                max_health: 75.0,
-                faction: Faction::Cartel,
-                unit_type: UnitType::Roadblock,
-                damage: 0.0,
-                range: 0.0,
-                movement_speed: 0.0,
-                target: None,
-                attack_cooldown: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+            PlayerCommand::CallReinforcements => {
+                // Gunmen can only be raised in districts the cartel holds,
+                // the one closest to Ovidio first
+                let rally = layout.ovidio_position();
+                let Some(zone) = zone_query.iter()
+                    .filter(|(_, control)| control.owner == Faction::Cartel)
+                    .map(|(zone, _)| zone)
+                    .min_by(|a, b| a.center.distance(rally).total_cmp(&b.center.distance(rally)))
+                else {
+                    warn!("📵 No cartel-held zone left to send reinforcements from!");
+                    continue;
+                };
+                if let Err(denied) = resources.try_spend(&rules, CartelAction::Reinforcements) {
+                    warn!("📞 Cannot call reinforcements: {}", denied);
+                    continue;
+                }
+                
+                let spawn_positions = [
+                    zone.center + Vec3::new(0.0, 40.0, 0.0),
+                    zone.center + Vec3::new(40.0, -20.0, 0.0),
+                    zone.center + Vec3::new(-40.0, -20.0, 0.0),
+                ];
+                
+                for (i, position) in spawn_positions.iter().enumerate() {
+                    let unit_type = if i == 0 { UnitType::Enforcer } else { UnitType::Sicario };
+                    spawn_unit(&mut commands, &registry, unit_type, Faction::Cartel, *position);
+                    
+                    // Spawn arrival particles
+                    for _ in 0..8 {
+                        let velocity = Vec3::new(
+                            rng.rng.gen_range(-120.0..120.0),
+                            rng.rng.gen_range(-120.0..120.0),
+                            0.0,
+                        );
+                        
+                        commands.spawn((
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    color: Color::rgb(0.9, 0.2, 0.2),
+                                    custom_size: Some(Vec2::new(4.0, 4.0)),
+                                    ..default()
+                                },
+                                transform: Transform::from_translation(world_to_iso(*position) + Vec3::new(0.0, 0.0, 2.0)),
+                                ..default()
+                            },
+                            ParticleEffect {
+                                lifetime: Timer::new(Duration::from_millis(600), TimerMode::Once),
+                                velocity,
+                            },
+                        ));
+                    }
+                }
+                
+                info!("� *ENGINE REVVING* �📱 REINFORCEMENTS arriving from {}! 📻 '¡Necesitamos más hombres!'", zone.name);
+            },
+            PlayerCommand::Move { units, target } => {
+                for entity in units {
+                    if let Ok((mut unit, mut movement)) = unit_query.get_mut(entity) {
+                        unit.target = None;
+                        movement.target_position = Some(target);
+                        commands.entity(entity).remove::<AttackOrder>();
+                    }
+                }
+            },
+            PlayerCommand::Escape { route } => {
+                capture::start_escape(&mut game_state, &metadata, &layout, route);
+            },
+            PlayerCommand::Attack { units, target } => {
+                for entity in units {
+                    if let Ok((mut unit, mut movement)) = unit_query.get_mut(entity) {
+                        unit.target = Some(target);
+                        movement.target_position = None;
+                        commands.entity(entity).insert(AttackOrder);
+                    }
+                }
             },
-        )).id();
-        
-        // Add roadblock label
-        commands.spawn(Text2dBundle {
-            text: Text::from_section(
-                "🚧 ROADBLOCK",
-                TextStyle {
-                    font_size: 10.0,
-                    color: Color::ORANGE,
-                    ..default()
-                },
-            ),
-            transform: Transform::from_translation(position + Vec3::new(0.0, 20.0, 1.0)),
-            ..default()
-        });
-        
-        // Spawn construction particles
-        for _ in 0..5 {
-            let velocity = Vec3::new(
-                thread_rng().gen_range(-80.0..80.0),
-                thread_rng().gen_range(-80.0..80.0),
-                0.0,
-            );
-            
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        color: Color::rgb(0.8, 0.6, 0.2),
-                        custom_size: Some(Vec2::new(3.0, 3.0)),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(position + Vec3::new(0.0, 0.0, 2.0)),
-                    ..default()
-                },
-                ParticleEffect {
-                    lifetime: Timer::new(Duration::from_millis(400), TimerMode::Once),
-                    velocity,
-                },
-            ));
         }
-        
-        info!("� *CONSTRUCTION SOUNDS* �🛑 ROADBLOCK deployed! Military convoy movement disrupted 📻 'Cartel blocking the roads!'");
-        game_state.cartel_score += 5;
     }
-    
-    if input.just_pressed(KeyCode::R) {
-        // Call reinforcements with enhanced spawning
-        let spawn_positions = vec![
-            Vec3::new(-400.0, 200.0, 0.0),
-            Vec3::new(-350.0, 150.0, 0.0),
-            Vec3::new(-400.0, 100.0, 0.0),
-        ];
+}
+
+fn handle_input(
+    input: Res<Input<KeyCode>>,
+    side: Res<PlayerSide>,
+    mut player_commands: ResMut<PlayerCommands>,
+    mut save_requests: ResMut<SaveRequests>,
+) {
+    // Cartel actions belong to the cartel player; otherwise the commander has them
+    if *side == PlayerSide::Cartel {
+        if input.just_pressed(KeyCode::R) {
+            player_commands.push(PlayerCommand::CallReinforcements);
+        }
         
-        for (i, position) in spawn_positions.iter().enumerate() {
-            let unit_type = if i == 0 { UnitType::Enforcer } else { UnitType::Sicario };
-            spawn_unit(&mut commands, unit_type, Faction::Cartel, *position);
-            
-            // Spawn arrival particles
-            for _ in 0..8 {
-                let velocity = Vec3::new(
-                    thread_rng().gen_range(-120.0..120.0),
-                    thread_rng().gen_range(-120.0..120.0),
-                    0.0,
-                );
-                
-                commands.spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            color: Color::rgb(0.9, 0.2, 0.2),
-                            custom_size: Some(Vec2::new(4.0, 4.0)),
-                            ..default()
-                        },
-                        transform: Transform::from_translation(*position + Vec3::new(0.0, 0.0, 2.0)),
-                        ..default()
-                    },
-                    ParticleEffect {
-                        lifetime: Timer::new(Duration::from_millis(600), TimerMode::Once),
-                        velocity,
-                    },
-                ));
+        // Escape routes, in the order of the Ovidio metadata
+        for (route, key) in [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3].into_iter().enumerate() {
+            if input.just_pressed(key) {
+                player_commands.push(PlayerCommand::Escape { route });
             }
         }
-        
-        info!("� *ENGINE REVVING* �📱 REINFORCEMENTS arriving! Cartel sends backup to the safehouse 📻 '¡Necesitamos más hombres!'");
-        game_state.cartel_score += 10;
     }
     
-    if input.just_pressed(KeyCode::Escape) {
-        info!("🏛️ SIMULATION ENDED");
-        info!("📚 Historical Note: The real Battle of Culiacán ended with the government releasing Ovidio Guzmán López");
-        info!("⚖️ This demonstrated the complex balance of power between organized crime and the state in Mexico");
-        std::process::exit(0);
+    // Quicksave / quickload, applied at the next fixed tick
+    if input.just_pressed(KeyCode::F5) {
+        save_requests.save = Some(QUICKSAVE_PATH.to_string());
+    }
+    
+    if input.just_pressed(KeyCode::F9) {
+        save_requests.load = Some(QUICKSAVE_PATH.to_string());
     }
     
     // Debug keys
     if input.just_pressed(KeyCode::F1) {
         info!("🎮 ENHANCED CONTROLS:");
-        info!("SPACE - Deploy roadblock with construction effects");
-        info!("R - Call reinforcements with arrival particles");  
-        info!("ESC - End simulation");
+        info!("LEFT CLICK / DRAG - Select your units (SHIFT adds to selection)");
+        info!("RIGHT CLICK - Move selected units, or attack the enemy under the cursor");
+        info!("F3 - Show what each unit's AI has decided");
+        info!("SPACE - Place a roadblock at the cursor (LEFT CLICK builds, RIGHT CLICK cancels) (cartel)");
+        info!("R - Call reinforcements with arrival particles (cartel)");  
+        info!("1 / 2 / 3 - Move Ovidio out via the airport, highway north or river crossing (cartel)");
+        info!("F5 / F9 - Quicksave / quickload");
+        info!("ESC / P - Pause");
         info!("F1 - Show this help");
         info!("📊 Graphics: Health bars, damage indicators, particle effects");
         info!("🎨 Visual: Unit icons, labels, explosion effects");
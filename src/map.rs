@@ -0,0 +1,402 @@
+// ==================== MAP LAYOUT ====================
+// Typed model of assets/maps/culiacan_map_layout.json plus the loader that
+// turns it into the battlefield. Map coordinates are in map units with the
+// origin in the top-left corner; the game works in centered world units.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Faction, UnitType};
+
+pub const MAP_LAYOUT_PATH: &str = "assets/maps/culiacan_map_layout.json";
+
+// Shipped copy of the layout, used when the file on disk is missing or broken
+const BUILTIN_MAP_LAYOUT: &str = include_str!("../assets/maps/culiacan_map_layout.json");
+
+// ==================== MAP MODEL ====================
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct MapLayout {
+    pub map_info: MapInfo,
+    pub zones: Vec<ZoneData>,
+    pub strategic_points: Vec<StrategicPointData>,
+    #[serde(default)]
+    pub obstacles: Vec<ObstacleData>,
+    #[serde(default)]
+    pub roads: Vec<RoadData>,
+    pub spawn_points: SpawnPoints,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct MapInfo {
+    pub name: String,
+    pub bounds: MapBounds,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct MapBounds {
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZoneData {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub zone_type: String,
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+    pub initial_control: String,
+    pub strategic_value: f32,
+    pub civilian_density: f32,
+    pub infrastructure_level: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StrategicPointData {
+    pub id: String,
+    pub name: String,
+    pub position: [f32; 2],
+    pub importance: f32,
+    pub requires_holding_time: f32,
+    pub defensive_bonus: f32,
+}
+
+// Impassable terrain; the nav grid blocks every cell whose center lies inside
+#[derive(Deserialize, Clone, Debug)]
+pub struct ObstacleData {
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+}
+
+// A road as a polyline of map positions
+#[derive(Deserialize, Clone, Debug)]
+pub struct RoadData {
+    pub points: Vec<[f32; 2]>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SpawnPoints {
+    pub cartel: Vec<SpawnPointData>,
+    pub military: Vec<SpawnPointData>,
+    pub ovidio: OvidioSpawn,
+    // Named map edges where military waves come in
+    #[serde(default)]
+    pub entry_points: Vec<EntryPointData>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SpawnPointData {
+    pub position: [f32; 2],
+    pub unit_type: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EntryPointData {
+    pub id: String,
+    pub name: String,
+    pub position: [f32; 2],
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct OvidioSpawn {
+    pub position: [f32; 2],
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ZoneType {
+    TresRios,
+    MilitaryBase,
+    CityCenter,
+    Airport,
+    Highway,
+}
+
+// A map spawn entry that resolved to a known unit type
+pub struct UnitSpawn {
+    pub unit_type: UnitType,
+    pub faction: Faction,
+    pub position: Vec3,
+}
+
+// ==================== COMPONENTS ====================
+
+#[derive(Component, Clone, Debug)]
+pub struct Zone {
+    pub id: String,
+    pub name: String,
+    pub zone_type: ZoneType,
+    pub center: Vec3,
+    pub size: Vec2,
+    pub initial_control: Faction,
+    pub strategic_value: f32,
+    pub civilian_density: f32,
+    pub infrastructure_level: f32,
+}
+
+// ==================== ERRORS ====================
+
+#[derive(Debug)]
+pub enum MapError {
+    Io { path: String, source: std::io::Error },
+    Parse { path: String, source: serde_json::Error },
+    UnknownZoneType { zone_id: String, zone_type: String },
+    UnknownControl { zone_id: String, control: String },
+    UnknownUnitType { group: &'static str, index: usize, unit_type: String },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapError::Io { path, source } => write!(f, "cannot read map '{}': {}", path, source),
+            MapError::Parse { path, source } => write!(f, "invalid map '{}': {}", path, source),
+            MapError::UnknownZoneType { zone_id, zone_type } => write!(
+                f,
+                "zone '{}' has unknown type \"{}\" (expected one of: {})",
+                zone_id, zone_type, ZoneType::NAMES.join(", ")
+            ),
+            MapError::UnknownControl { zone_id, control } => write!(
+                f,
+                "zone '{}' has unknown initial_control \"{}\" (expected SinaloaCartel, MexicanMilitary or Civilian)",
+                zone_id, control
+            ),
+            MapError::UnknownUnitType { group, index, unit_type } => write!(
+                f,
+                "spawn_points.{}[{}] has unknown unit_type \"{}\" (expected one of: {})",
+                group, index, unit_type, UnitType::NAMES.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+// ==================== NAME PARSING ====================
+
+impl ZoneType {
+    pub const NAMES: [&'static str; 5] = ["TresRíos", "MilitaryBase", "CityCenter", "Airport", "Highway"];
+}
+
+impl FromStr for ZoneType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TresRíos" | "TresRios" => Ok(ZoneType::TresRios),
+            "MilitaryBase" => Ok(ZoneType::MilitaryBase),
+            "CityCenter" => Ok(ZoneType::CityCenter),
+            "Airport" => Ok(ZoneType::Airport),
+            "Highway" => Ok(ZoneType::Highway),
+            _ => Err(()),
+        }
+    }
+}
+
+fn parse_control(name: &str) -> Option<Faction> {
+    match name {
+        "SinaloaCartel" => Some(Faction::Cartel),
+        "MexicanMilitary" => Some(Faction::Military),
+        "Civilian" => Some(Faction::Civilian),
+        _ => None,
+    }
+}
+
+// ==================== LOADING ====================
+
+pub fn load_map(path: &str) -> Result<MapLayout, MapError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| MapError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    parse_map(path, &contents)
+}
+
+fn parse_map(path: &str, contents: &str) -> Result<MapLayout, MapError> {
+    serde_json::from_str(contents).map_err(|source| MapError::Parse {
+        path: path.to_string(),
+        source,
+    })
+}
+
+// Loads the layout from disk, falling back to the copy compiled into the binary
+pub fn load_map_or_builtin(path: &str) -> MapLayout {
+    match load_map(path) {
+        Ok(layout) => {
+            info!("🗺️  Loaded map '{}' from {}", layout.map_info.name, path);
+            layout
+        }
+        Err(err) => {
+            error!("❌ {}", err);
+            warn!("🗺️  Falling back to the built-in Culiacán layout");
+            parse_map("<built-in>", BUILTIN_MAP_LAYOUT).expect("built-in map layout must be valid")
+        }
+    }
+}
+
+impl MapLayout {
+    // Converts map coordinates (origin top-left, y down) to world coordinates
+    pub fn to_world(&self, position: [f32; 2]) -> Vec3 {
+        Vec3::new(
+            position[0] - self.map_info.bounds.width * 0.5,
+            self.map_info.bounds.height * 0.5 - position[1],
+            0.0,
+        )
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.map_info.bounds.width, self.map_info.bounds.height)
+    }
+
+    pub fn ovidio_position(&self) -> Vec3 {
+        self.to_world(self.spawn_points.ovidio.position)
+    }
+
+    // Every straight road segment, in world coordinates
+    pub fn road_segments(&self) -> Vec<(Vec3, Vec3)> {
+        self.roads.iter()
+            .flat_map(|road| road.points.windows(2).map(|pair| (self.to_world(pair[0]), self.to_world(pair[1]))))
+            .collect()
+    }
+
+    // World position of the zone or strategic point with this id
+    pub fn landmark(&self, id: &str) -> Option<Vec3> {
+        self.zones.iter()
+            .find(|zone| zone.id == id)
+            .map(|zone| zone.position)
+            .or_else(|| self.strategic_points.iter().find(|point| point.id == id).map(|point| point.position))
+            .map(|position| self.to_world(position))
+    }
+
+    // World position and display name of an entry point, zone or strategic point
+    pub fn entry_point(&self, id: &str) -> Option<(Vec3, &str)> {
+        if let Some(entry) = self.spawn_points.entry_points.iter().find(|entry| entry.id == id) {
+            return Some((self.to_world(entry.position), entry.name.as_str()));
+        }
+        self.zones.iter()
+            .find(|zone| zone.id == id)
+            .map(|zone| (zone.position, zone.name.as_str()))
+            .or_else(|| self.strategic_points.iter().find(|point| point.id == id).map(|point| (point.position, point.name.as_str())))
+            .map(|(position, name)| (self.to_world(position), name))
+    }
+
+    // Resolves every zone, reporting the ones whose type or control is unknown
+    pub fn zones(&self) -> (Vec<Zone>, Vec<MapError>) {
+        let mut zones = Vec::new();
+        let mut errors = Vec::new();
+
+        for data in &self.zones {
+            let Ok(zone_type) = data.zone_type.parse::<ZoneType>() else {
+                errors.push(MapError::UnknownZoneType {
+                    zone_id: data.id.clone(),
+                    zone_type: data.zone_type.clone(),
+                });
+                continue;
+            };
+            let Some(initial_control) = parse_control(&data.initial_control) else {
+                errors.push(MapError::UnknownControl {
+                    zone_id: data.id.clone(),
+                    control: data.initial_control.clone(),
+                });
+                continue;
+            };
+
+            zones.push(Zone {
+                id: data.id.clone(),
+                name: data.name.clone(),
+                zone_type,
+                center: self.to_world(data.position),
+                size: Vec2::new(data.size[0], data.size[1]),
+                initial_control,
+                strategic_value: data.strategic_value,
+                civilian_density: data.civilian_density,
+                infrastructure_level: data.infrastructure_level,
+            });
+        }
+
+        (zones, errors)
+    }
+
+    // Resolves the faction spawn lists, reporting unit types the game does not know
+    pub fn unit_spawns(&self) -> (Vec<UnitSpawn>, Vec<MapError>) {
+        let mut spawns = Vec::new();
+        let mut errors = Vec::new();
+
+        let groups = [
+            ("cartel", Faction::Cartel, &self.spawn_points.cartel),
+            ("military", Faction::Military, &self.spawn_points.military),
+        ];
+
+        for (group, faction, points) in groups {
+            for (index, point) in points.iter().enumerate() {
+                match point.unit_type.parse::<UnitType>() {
+                    Ok(unit_type) => spawns.push(UnitSpawn {
+                        unit_type,
+                        faction: faction.clone(),
+                        position: self.to_world(point.position),
+                    }),
+                    Err(()) => errors.push(MapError::UnknownUnitType {
+                        group,
+                        index,
+                        unit_type: point.unit_type.clone(),
+                    }),
+                }
+            }
+        }
+
+        (spawns, errors)
+    }
+}
+
+// Axis-aligned size of a world-space rectangle after isometric projection
+pub fn iso_extent(size: Vec2) -> Vec2 {
+    Vec2::new((size.x + size.y) * 0.5, (size.x + size.y) * 0.3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builtin() -> MapLayout {
+        parse_map("<built-in>", BUILTIN_MAP_LAYOUT).expect("built-in map layout must be valid")
+    }
+
+    #[test]
+    fn missing_map_falls_back_to_the_builtin_layout() {
+        let layout = load_map_or_builtin("assets/maps/does_not_exist.json");
+        assert_eq!(layout.map_info.name, builtin().map_info.name);
+        assert!(matches!(load_map("assets/maps/does_not_exist.json"), Err(MapError::Io { .. })));
+        assert!(matches!(parse_map("broken.json", "{"), Err(MapError::Parse { .. })));
+    }
+
+    #[test]
+    fn builtin_layout_resolves_every_zone_and_spawn() {
+        let layout = builtin();
+        let (zones, zone_errors) = layout.zones();
+        assert!(zone_errors.is_empty());
+        assert_eq!(zones.len(), layout.zones.len());
+        let (spawns, spawn_errors) = layout.unit_spawns();
+        assert!(spawn_errors.is_empty());
+        assert_eq!(spawns.len(), layout.spawn_points.cartel.len() + layout.spawn_points.military.len());
+    }
+
+    #[test]
+    fn landmark_finds_zones_and_strategic_points_in_world_units() {
+        let layout = builtin();
+        assert_eq!(layout.landmark("tres_rios"), Some(Vec3::new(-400.0, 100.0, 0.0)));
+        assert_eq!(layout.landmark("intersection_1"), Some(Vec3::new(-300.0, 120.0, 0.0)));
+        assert_eq!(layout.landmark("north_approach"), None);
+        assert_eq!(layout.landmark("nowhere"), None);
+    }
+
+    #[test]
+    fn entry_point_prefers_entry_points_then_falls_back_to_landmarks() {
+        let layout = builtin();
+        assert_eq!(layout.entry_point("north_approach"), Some((Vec3::new(600.0, 300.0, 0.0), "North approach")));
+        assert_eq!(layout.entry_point("tres_rios"), Some((Vec3::new(-400.0, 100.0, 0.0), "Tres Ríos")));
+        assert_eq!(layout.entry_point("intersection_1"), Some((Vec3::new(-300.0, 120.0, 0.0), "Main Intersection")));
+        assert_eq!(layout.entry_point("nowhere"), None);
+    }
+}
@@ -0,0 +1,627 @@
+// ==================== MISSION SCRIPT ====================
+// A scenario file describes the mission as phases and triggers. Each phase
+// lists objectives and moves on to the next once enough of them are met;
+// failure conditions end the match early. Triggers fire their actions when
+// all of their conditions become true: spawn units, send a message, change
+// phase, add political pressure, launch a wave or end the mission.
+// assets/data/scenarios/historical.json is the Culiacanazo as it happened;
+// `--scenario <path>` plays a what-if instead.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::data;
+use crate::map::{MapLayout, Zone};
+use crate::ovidio::OvidioMetadata;
+use crate::placement::snap_to_road;
+use crate::pressure::{is_military_unit, PoliticalPressure};
+use crate::strategic::StrategicPoint;
+use crate::unit_stats::UnitRegistry;
+use crate::zones::ZoneControl;
+use crate::{
+    spawn_unit, Faction, GamePhase, GameState, MatchOutcome, MatchStats, Objective, ObjectiveType, Unit, UnitType,
+    WaveSpawner, WorldPosition,
+};
+
+pub const SCENARIO_PATH: &str = "assets/data/scenarios/historical.json";
+
+const BUILTIN_SCENARIO: &str = include_str!("../assets/data/scenarios/historical.json");
+
+// Gap between units spawned together
+const SPAWN_SPACING: f32 = 30.0;
+// Seconds a mission message stays on screen
+const MESSAGE_SECONDS: f32 = 8.0;
+
+// A place on the map: a landmark id, a mission objective or a map position
+#[derive(Deserialize, Clone, Debug)]
+pub struct Area {
+    #[serde(default)]
+    pub landmark: Option<String>,
+    #[serde(default)]
+    pub objective: Option<ObjectiveType>,
+    #[serde(default)]
+    pub position: Option<[f32; 2]>,
+    #[serde(default)]
+    pub radius: f32,
+}
+
+// Something about the match that is either true or not, checked every tick
+#[derive(Deserialize, Clone, Debug)]
+pub enum Condition {
+    // Seconds since the match began, and since the current phase began
+    MissionTime(f32),
+    PhaseTime(f32),
+    Phase(GamePhase),
+    WaveReached(u32),
+    MilitaryPresent,
+    OvidioDetained,
+    // Map roads with a cartel roadblock standing on them
+    RoadsBlocked(u32),
+    SoldiersLost(u32),
+    // Share of the release threshold
+    PressureAtLeast(f32),
+    PointsHeld(u32),
+    // Every cartel gunman is down
+    NoDefenders,
+    // Units of a faction on the map, optionally of one type
+    UnitsAtLeast { faction: Faction, #[serde(default)] unit_type: Option<UnitType>, count: u32 },
+    UnitsAtMost { faction: Faction, #[serde(default)] unit_type: Option<UnitType>, count: u32 },
+    ZoneControlled { zone: String, faction: Faction },
+    // A unit of the faction is inside the area
+    UnitInArea { faction: Faction, #[serde(default)] unit_type: Option<UnitType>, area: Area },
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum MissionAction {
+    // Logged as radio traffic and shown under the objectives
+    Message(String),
+    Spawn { faction: Faction, unit_type: UnitType, count: u32, at: Area },
+    ChangePhase(GamePhase),
+    // Added straight to political pressure
+    Pressure(f32),
+    // Sends the next military wave right away
+    NextWave,
+    EndMission(MatchOutcome),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct MissionObjective {
+    pub description: String,
+    pub condition: Condition,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PhaseScript {
+    pub phase: GamePhase,
+    pub title: String,
+    pub objectives: Vec<MissionObjective>,
+    // Objectives needed to move on; the last phase never moves on
+    pub required: usize,
+    // Any of these ends the match as a cartel defeat
+    #[serde(default)]
+    pub failures: Vec<MissionObjective>,
+    #[serde(default)]
+    pub on_enter: Vec<MissionAction>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Trigger {
+    pub id: String,
+    // Fires when all of these become true
+    pub when: Vec<Condition>,
+    // Fires again each time the conditions become true after lapsing
+    #[serde(default)]
+    pub repeat: bool,
+    pub actions: Vec<MissionAction>,
+}
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct MissionScript {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub phases: Vec<PhaseScript>,
+    #[serde(default)]
+    pub triggers: Vec<Trigger>,
+}
+
+// Where the mission stands; lives in GameState so it is saved with it
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct MissionProgress {
+    pub phase_time: f32,
+    // Objectives of the current phase met so far; met objectives stay met
+    pub objectives_met: Vec<bool>,
+    #[serde(default)]
+    pub triggers: Vec<TriggerProgress>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub message_time: f32,
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct TriggerProgress {
+    pub fired: bool,
+    // Conditions held last tick, so a trigger fires on the change
+    pub held: bool,
+}
+
+impl MissionProgress {
+    // Shows a message under the objectives for a few seconds
+    pub fn show_message(&mut self, message: &str) {
+        self.message = Some(message.to_string());
+        self.message_time = MESSAGE_SECONDS;
+    }
+}
+
+impl MissionScript {
+    pub fn phase(&self, phase: &GamePhase) -> Option<&PhaseScript> {
+        self.phases.iter().find(|script| script.phase == *phase)
+    }
+
+    fn next_after(&self, phase: &GamePhase) -> Option<&PhaseScript> {
+        let index = self.phases.iter().position(|script| script.phase == *phase)?;
+        self.phases.get(index + 1)
+    }
+
+    // Everything wrong with the script that parsing cannot catch
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.phases.is_empty() {
+            problems.push("no phases".to_string());
+        }
+
+        let mut phases = Vec::new();
+        for phase in &self.phases {
+            if phases.contains(&&phase.phase) {
+                problems.push(format!("phase {:?} appears twice", phase.phase));
+            }
+            phases.push(&phase.phase);
+            // Entering a phase runs on_enter, so a phase change there could loop forever
+            if phase.on_enter.iter().any(|action| matches!(action, MissionAction::ChangePhase(_))) {
+                problems.push(format!("phase {:?} changes phase on entering, use a trigger instead", phase.phase));
+            }
+            if phase.required > phase.objectives.len() {
+                problems.push(format!(
+                    "phase {:?} requires {} of only {} objectives",
+                    phase.phase, phase.required, phase.objectives.len()
+                ));
+            }
+        }
+
+        let mut ids = HashSet::new();
+        for trigger in &self.triggers {
+            if !ids.insert(trigger.id.as_str()) {
+                problems.push(format!("trigger '{}' appears twice", trigger.id));
+            }
+        }
+
+        let conditions = self.phases.iter()
+            .flat_map(|phase| phase.objectives.iter().chain(&phase.failures))
+            .map(|objective| &objective.condition)
+            .chain(self.triggers.iter().flat_map(|trigger| &trigger.when));
+        for condition in conditions {
+            if let Condition::UnitInArea { area, .. } = condition {
+                check_area(area, &mut problems);
+            }
+        }
+
+        let actions = self.phases.iter()
+            .flat_map(|phase| &phase.on_enter)
+            .chain(self.triggers.iter().flat_map(|trigger| &trigger.actions));
+        for action in actions {
+            match action {
+                MissionAction::Spawn { at, .. } => check_area(at, &mut problems),
+                MissionAction::ChangePhase(phase) if self.phase(phase).is_none() => {
+                    problems.push(format!("ChangePhase to {:?}, which has no script", phase));
+                }
+                _ => {}
+            }
+        }
+
+        problems
+    }
+}
+
+fn check_area(area: &Area, problems: &mut Vec<String>) {
+    let anchors = [area.landmark.is_some(), area.objective.is_some(), area.position.is_some()];
+    if anchors.iter().filter(|set| **set).count() != 1 {
+        problems.push(format!("area {:?} needs exactly one of landmark, objective or position", area));
+    }
+}
+
+// ==================== LOADING ====================
+
+// The historical scenario is the built-in fallback
+pub fn load_scenario_or_builtin(path: &str) -> MissionScript {
+    data::load_json_or_builtin(path, BUILTIN_SCENARIO, MissionScript::problems)
+}
+
+// ==================== CONDITIONS ====================
+
+// Everything the conditions look at, gathered once per tick
+struct MissionContext<'a> {
+    layout: &'a MapLayout,
+    mission_time: f32,
+    phase_time: f32,
+    phase: GamePhase,
+    wave: u32,
+    ovidio_detained: bool,
+    roads_blocked: u32,
+    soldiers_lost: u32,
+    pressure: f32,
+    points_held: u32,
+    units: Vec<(Faction, UnitType, Vec3)>,
+    zones: Vec<(String, Faction)>,
+    objectives: Vec<(ObjectiveType, Vec3)>,
+}
+
+impl MissionContext<'_> {
+    fn area_center(&self, area: &Area) -> Option<Vec3> {
+        if let Some(landmark) = &area.landmark {
+            return self.layout.landmark(landmark);
+        }
+        if let Some(objective_type) = &area.objective {
+            return self.objectives.iter()
+                .find(|(kind, _)| kind == objective_type)
+                .map(|(_, position)| *position);
+        }
+        area.position.map(|position| self.layout.to_world(position))
+    }
+
+    fn count(&self, faction: &Faction, unit_type: &Option<UnitType>) -> u32 {
+        self.units.iter()
+            .filter(|(unit_faction, kind, _)| unit_faction == faction && unit_type.as_ref().is_none_or(|wanted| kind == wanted))
+            .count() as u32
+    }
+}
+
+impl Condition {
+    fn is_met(&self, context: &MissionContext) -> bool {
+        match self {
+            Condition::MissionTime(seconds) => context.mission_time >= *seconds,
+            Condition::PhaseTime(seconds) => context.phase_time >= *seconds,
+            Condition::Phase(phase) => context.phase == *phase,
+            Condition::WaveReached(wave) => context.wave >= *wave,
+            Condition::MilitaryPresent => context.count(&Faction::Military, &None) > 0,
+            Condition::OvidioDetained => context.ovidio_detained,
+            Condition::RoadsBlocked(count) => context.roads_blocked >= *count,
+            Condition::SoldiersLost(count) => context.soldiers_lost >= *count,
+            Condition::PressureAtLeast(share) => context.pressure >= *share,
+            Condition::PointsHeld(count) => context.points_held >= *count,
+            Condition::NoDefenders => !context.units.iter().any(|(faction, kind, _)| {
+                *faction == Faction::Cartel && !matches!(kind, UnitType::Roadblock | UnitType::Ovidio)
+            }),
+            Condition::UnitsAtLeast { faction, unit_type, count } => context.count(faction, unit_type) >= *count,
+            Condition::UnitsAtMost { faction, unit_type, count } => context.count(faction, unit_type) <= *count,
+            Condition::ZoneControlled { zone, faction } => context.zones.iter().any(|(id, owner)| id == zone && owner == faction),
+            Condition::UnitInArea { faction, unit_type, area } => {
+                let Some(center) = context.area_center(area) else {
+                    return false;
+                };
+                context.units.iter().any(|(unit_faction, kind, position)| {
+                    unit_faction == faction
+                        && unit_type.as_ref().is_none_or(|wanted| kind == wanted)
+                        && position.distance(center) <= area.radius
+                })
+            }
+        }
+    }
+}
+
+// Roads with a cartel roadblock on them
+fn roads_blocked(layout: &MapLayout, roadblocks: &[Vec3]) -> u32 {
+    layout.roads.iter()
+        .filter(|road| {
+            let segments: Vec<(Vec3, Vec3)> = road.points.windows(2)
+                .map(|pair| (layout.to_world(pair[0]), layout.to_world(pair[1])))
+                .collect();
+            roadblocks.iter().any(|roadblock| snap_to_road(&segments, *roadblock).is_some())
+        })
+        .count() as u32
+}
+
+// ==================== SYSTEMS ====================
+
+// What the actions of one tick may touch
+struct ActionTargets<'a, 'w, 's> {
+    commands: &'a mut Commands<'w, 's>,
+    registry: &'a UnitRegistry,
+    pressure: &'a mut PoliticalPressure,
+    // Launched once the actions are done
+    next_wave: bool,
+}
+
+fn run_action(
+    action: &MissionAction,
+    script: &MissionScript,
+    context: &MissionContext,
+    game_state: &mut GameState,
+    targets: &mut ActionTargets,
+) {
+    match action {
+        MissionAction::Message(message) => {
+            info!("📻 {}", message);
+            game_state.mission.show_message(message);
+        }
+        MissionAction::Spawn { faction, unit_type, count, at } => {
+            let Some(center) = context.area_center(at) else {
+                warn!("📋 Cannot spawn {:?}: the area {:?} is not on this map", unit_type, at);
+                return;
+            };
+            // One behind the other
+            for i in 0..*count {
+                let position = center + Vec3::new(i as f32 * SPAWN_SPACING, 0.0, 0.0);
+                spawn_unit(targets.commands, targets.registry, unit_type.clone(), faction.clone(), position);
+            }
+            info!("📋 {} {:?} {:?} arrive", count, faction, unit_type);
+        }
+        MissionAction::ChangePhase(phase) => {
+            if let Some(next) = script.phase(phase) {
+                enter_phase(next, script, context, game_state, targets);
+            }
+        }
+        MissionAction::Pressure(amount) => targets.pressure.value += amount,
+        MissionAction::NextWave => targets.next_wave = true,
+        MissionAction::EndMission(outcome) => game_state.scripted_outcome = Some(*outcome),
+    }
+}
+
+fn enter_phase(
+    phase: &PhaseScript,
+    script: &MissionScript,
+    context: &MissionContext,
+    game_state: &mut GameState,
+    targets: &mut ActionTargets,
+) {
+    info!("{}", phase.title);
+    game_state.game_phase = phase.phase.clone();
+    game_state.mission.phase_time = 0.0;
+    game_state.mission.objectives_met.clear();
+    for action in &phase.on_enter {
+        run_action(action, script, context, game_state, targets);
+    }
+}
+
+// What conditions are checked against and actions draw on
+#[derive(SystemParam)]
+pub struct MissionData<'w> {
+    time: Res<'w, Time>,
+    script: Res<'w, MissionScript>,
+    registry: Res<'w, UnitRegistry>,
+    layout: Res<'w, MapLayout>,
+    metadata: Res<'w, OvidioMetadata>,
+    stats: Res<'w, MatchStats>,
+}
+
+#[derive(SystemParam)]
+pub struct MissionQueries<'w, 's> {
+    unit_query: Query<'w, 's, (&'static Unit, &'static WorldPosition)>,
+    point_query: Query<'w, 's, &'static StrategicPoint>,
+    zone_query: Query<'w, 's, (&'static Zone, &'static ZoneControl)>,
+    objective_query: Query<'w, 's, &'static Objective>,
+}
+
+pub fn mission_system(
+    mut commands: Commands,
+    data: MissionData,
+    queries: MissionQueries,
+    mut pressure: ResMut<PoliticalPressure>,
+    mut game_state: ResMut<GameState>,
+    mut wave_query: Query<&mut WaveSpawner>,
+) {
+    if game_state.outcome.is_some() {
+        return;
+    }
+    let MissionData { time, script, registry, layout, metadata, stats } = data;
+    let MissionQueries { unit_query, point_query, zone_query, objective_query } = queries;
+    let dt = time.delta_seconds();
+    game_state.mission.phase_time += dt;
+    game_state.mission.message_time = (game_state.mission.message_time - dt).max(0.0);
+
+    let roadblocks: Vec<Vec3> = unit_query.iter()
+        .filter(|(unit, _)| unit.faction == Faction::Cartel && unit.unit_type == UnitType::Roadblock)
+        .map(|(_, position)| position.0)
+        .collect();
+    let context = MissionContext {
+        layout: &layout,
+        mission_time: game_state.mission_timer,
+        phase_time: game_state.mission.phase_time,
+        phase: game_state.game_phase.clone(),
+        wave: game_state.current_wave,
+        ovidio_detained: game_state.ovidio_status.in_custody(),
+        roads_blocked: roads_blocked(&layout, &roadblocks),
+        soldiers_lost: stats.units_lost.iter()
+            .filter(|(unit_type, _)| is_military_unit(unit_type))
+            .map(|(_, count)| count)
+            .sum(),
+        pressure: pressure.progress(&metadata),
+        points_held: point_query.iter().filter(|point| point.owned_by(&Faction::Cartel)).count() as u32,
+        units: unit_query.iter()
+            .map(|(unit, position)| (unit.faction.clone(), unit.unit_type.clone(), position.0))
+            .collect(),
+        zones: zone_query.iter()
+            .map(|(zone, control)| (zone.id.clone(), control.owner.clone()))
+            .collect(),
+        objectives: objective_query.iter()
+            .map(|objective| (objective.objective_type.clone(), objective.position))
+            .collect(),
+    };
+    let mut targets = ActionTargets {
+        commands: &mut commands,
+        registry: &registry,
+        pressure: &mut pressure,
+        next_wave: false,
+    };
+
+    // A scenario need not open with the preparation phase
+    if script.phase(&game_state.game_phase).is_none() {
+        enter_phase(&script.phases[0], &script, &context, &mut game_state, &mut targets);
+    } else {
+        run_script(&script, &context, &mut game_state, &mut targets);
+    }
+
+    // The wave spawner runs before the script, so the wave goes out next tick
+    if targets.next_wave {
+        for mut spawner in wave_query.iter_mut() {
+            let duration = spawner.next_wave_timer.duration();
+            spawner.next_wave_timer.set_elapsed(duration);
+        }
+    }
+}
+
+// Triggers first, then the current phase's failures and objectives
+fn run_script(script: &MissionScript, context: &MissionContext, game_state: &mut GameState, targets: &mut ActionTargets) {
+    game_state.mission.triggers.resize(script.triggers.len(), TriggerProgress::default());
+    for (index, trigger) in script.triggers.iter().enumerate() {
+        let holds = trigger.when.iter().all(|condition| condition.is_met(context));
+        let progress = &mut game_state.mission.triggers[index];
+        let fires = holds && !progress.held && (trigger.repeat || !progress.fired);
+        progress.held = holds;
+        if fires {
+            progress.fired = true;
+            for action in &trigger.actions {
+                run_action(action, script, context, game_state, targets);
+            }
+        }
+    }
+    if game_state.scripted_outcome.is_some() {
+        return;
+    }
+
+    // Triggers may have changed the phase
+    let Some(current) = script.phase(&game_state.game_phase) else {
+        return;
+    };
+    if let Some(failure) = current.failures.iter().find(|failure| failure.condition.is_met(context)) {
+        warn!("❌ MISSION FAILED: {}", failure.description);
+        game_state.scripted_outcome = Some(MatchOutcome::MissionFailed);
+        return;
+    }
+
+    let progress = &mut game_state.mission;
+    progress.objectives_met.resize(current.objectives.len(), false);
+    for (met, objective) in progress.objectives_met.iter_mut().zip(&current.objectives) {
+        if !*met && objective.condition.is_met(context) {
+            *met = true;
+            info!("✅ Objective complete: {}", objective.description);
+        }
+    }
+    if progress.objectives_met.iter().filter(|met| **met).count() < current.required {
+        return;
+    }
+    if let Some(next) = script.next_after(&current.phase) {
+        enter_phase(next, script, context, game_state, targets);
+    }
+}
+
+// ==================== HUD ====================
+
+#[derive(Component)]
+pub struct ObjectiveText;
+
+pub fn spawn_objective_panel(parent: &mut ChildBuilder) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(30.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::rgba(0.1, 0.1, 0.1, 0.7).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::rgb(0.9, 0.9, 0.7),
+                        ..default()
+                    },
+                ),
+                ObjectiveText,
+            ));
+        });
+}
+
+pub fn objective_hud_system(
+    script: Res<MissionScript>,
+    game_state: Res<GameState>,
+    mut text_query: Query<&mut Text, With<ObjectiveText>>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+    let Some(current) = script.phase(&game_state.game_phase) else {
+        text.sections[0].value.clear();
+        return;
+    };
+
+    let objectives: Vec<String> = current.objectives.iter()
+        .enumerate()
+        .map(|(i, objective)| {
+            let met = game_state.mission.objectives_met.get(i).copied().unwrap_or(false);
+            format!("{} {}", if met { "✅" } else { "⬜" }, objective.description)
+        })
+        .collect();
+    let choice = if current.required < current.objectives.len() {
+        format!(" (any {})", current.required)
+    } else {
+        String::new()
+    };
+    let mut line = format!("📋 Objectives{}: {}", choice, objectives.join(" | "));
+    if let Some(message) = game_state.mission.message.as_ref().filter(|_| game_state.mission.message_time > 0.0) {
+        line.push_str(&format!("  📻 {}", message));
+    }
+    text.sections[0].value = line;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataError;
+
+    fn parse(contents: &str) -> Result<MissionScript, DataError> {
+        data::parse_json("test", contents, MissionScript::problems)
+    }
+
+    #[test]
+    fn builtin_scenario_is_valid() {
+        let script = parse(BUILTIN_SCENARIO).expect("built-in scenario must be valid");
+        assert!(script.phase(&GamePhase::Preparation).is_some());
+    }
+
+    #[test]
+    fn phase_change_on_enter_is_rejected() {
+        let script = r#"{
+            "name": "loop",
+            "phases": [
+                {"phase": "Preparation", "title": "", "objectives": [], "required": 0,
+                 "on_enter": [{"ChangePhase": "InitialRaid"}]},
+                {"phase": "InitialRaid", "title": "", "objectives": [], "required": 0}
+            ]
+        }"#;
+        match parse(script) {
+            Err(DataError::Invalid { problems, .. }) => assert_eq!(problems, vec![
+                "phase Preparation changes phase on entering, use a trigger instead".to_string(),
+            ]),
+            other => panic!("expected the script to be rejected, got {:?}", other.map(|script| script.name)),
+        }
+
+        // The same change from a trigger is fine
+        let triggered = script
+            .replace(r#"[{"ChangePhase": "InitialRaid"}]},"#, "[]},")
+            .replacen(
+                r#""phases""#,
+                r#""triggers": [{"id": "go", "when": ["MilitaryPresent"], "actions": [{"ChangePhase": "InitialRaid"}]}], "phases""#,
+                1,
+            );
+        assert!(parse(&triggered).is_ok());
+    }
+}
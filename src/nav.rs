@@ -0,0 +1,489 @@
+// ==================== NAVIGATION ====================
+// Coarse navigation grid over the map in world units. Rivers and buildings
+// from the map file are permanently blocked, zones change traversal cost and
+// roadblocks block cells at runtime. Units follow A* paths across it.
+
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::map::MapLayout;
+
+pub const NAV_CELL_SIZE: f32 = 20.0;
+
+// Radius around a roadblock that becomes impassable
+pub const ROADBLOCK_BLOCK_RADIUS: f32 = 30.0;
+
+// Integer step costs keep the open list ordering exact
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+// Cell cost on highways, the cheapest ground on the map
+const HIGHWAY_COST: u32 = 6;
+
+#[derive(Resource, Clone)]
+pub struct NavGrid {
+    width: i32,
+    height: i32,
+    origin: Vec2,
+    static_blocked: Vec<bool>,
+    blockers: Vec<u16>,
+    cost: Vec<u32>,
+    // Bumped whenever runtime blockers change so cached paths get recomputed
+    pub version: u32,
+}
+
+// Path a unit is currently following, in world coordinates
+#[derive(Component, Default)]
+pub struct NavPath {
+    pub waypoints: Vec<Vec3>,
+    pub goal: Option<Vec3>,
+    pub grid_version: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct OpenNode {
+    f: u32,
+    g: u32,
+    index: usize,
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Min-heap on f, ties broken toward nodes closer to the goal
+        other.f.cmp(&self.f).then_with(|| self.g.cmp(&other.g))
+    }
+}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl NavGrid {
+    pub fn from_layout(layout: &MapLayout) -> Self {
+        let size = layout.size();
+        let width = (size.x / NAV_CELL_SIZE).ceil() as i32;
+        let height = (size.y / NAV_CELL_SIZE).ceil() as i32;
+        let cells = (width * height) as usize;
+
+        let mut grid = Self {
+            width,
+            height,
+            origin: -size * 0.5,
+            static_blocked: vec![false; cells],
+            blockers: vec![0; cells],
+            cost: vec![STRAIGHT_COST; cells],
+            version: 0,
+        };
+
+        let (zones, _) = layout.zones();
+        for zone in &zones {
+            // Dense neighbourhoods are slower to push through, highways faster
+            let multiplier = match zone.zone_type {
+                crate::map::ZoneType::Highway => HIGHWAY_COST as f32 / STRAIGHT_COST as f32,
+                _ => 1.0 + zone.civilian_density * 0.5,
+            };
+            let half = zone.size * 0.5;
+            grid.for_each_cell_in_rect(zone.center.truncate() - half, zone.center.truncate() + half, |grid, index| {
+                grid.cost[index] = (STRAIGHT_COST as f32 * multiplier).round() as u32;
+            });
+        }
+
+        for obstacle in &layout.obstacles {
+            let center = layout.to_world(obstacle.position).truncate();
+            let half = Vec2::new(obstacle.size[0], obstacle.size[1]) * 0.5;
+            grid.for_each_cell_in_rect(center - half, center + half, |grid, index| {
+                grid.static_blocked[index] = true;
+            });
+        }
+
+        grid
+    }
+
+    fn for_each_cell_in_rect(&mut self, min: Vec2, max: Vec2, mut f: impl FnMut(&mut Self, usize)) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let center = self.cell_center(IVec2::new(x, y)).truncate();
+                if center.cmpge(min).all() && center.cmple(max).all() {
+                    let index = self.index(IVec2::new(x, y));
+                    f(self, index);
+                }
+            }
+        }
+    }
+
+    fn index(&self, cell: IVec2) -> usize {
+        (cell.y * self.width + cell.x) as usize
+    }
+
+    fn in_bounds(&self, cell: IVec2) -> bool {
+        cell.x >= 0 && cell.y >= 0 && cell.x < self.width && cell.y < self.height
+    }
+
+    pub fn world_to_cell(&self, position: Vec3) -> IVec2 {
+        let local = (position.truncate() - self.origin) / NAV_CELL_SIZE;
+        IVec2::new(local.x.floor() as i32, local.y.floor() as i32)
+            .clamp(IVec2::ZERO, IVec2::new(self.width - 1, self.height - 1))
+    }
+
+    pub fn cell_center(&self, cell: IVec2) -> Vec3 {
+        let center = self.origin + (cell.as_vec2() + Vec2::splat(0.5)) * NAV_CELL_SIZE;
+        center.extend(0.0)
+    }
+
+    // Whether `position` lies on the map at all
+    pub fn contains(&self, position: Vec3) -> bool {
+        let local = position.truncate() - self.origin;
+        local.cmpge(Vec2::ZERO).all()
+            && local.x < self.width as f32 * NAV_CELL_SIZE
+            && local.y < self.height as f32 * NAV_CELL_SIZE
+    }
+
+    pub fn is_blocked(&self, cell: IVec2) -> bool {
+        if !self.in_bounds(cell) {
+            return true;
+        }
+        let index = self.index(cell);
+        self.static_blocked[index] || self.blockers[index] > 0
+    }
+
+    // Cells whose centers lie within `radius` of `position`
+    fn cells_in_radius(&self, position: Vec3, radius: f32) -> Vec<usize> {
+        let center = self.world_to_cell(position);
+        let reach = (radius / NAV_CELL_SIZE).ceil() as i32;
+        let mut cells = Vec::new();
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                let cell = center + IVec2::new(dx, dy);
+                if self.in_bounds(cell) && self.cell_center(cell).distance(position.truncate().extend(0.0)) <= radius {
+                    cells.push(self.index(cell));
+                }
+            }
+        }
+        cells
+    }
+
+    pub fn add_blocker(&mut self, position: Vec3, radius: f32) {
+        for index in self.cells_in_radius(position, radius) {
+            self.blockers[index] += 1;
+        }
+        self.version += 1;
+    }
+
+    pub fn remove_blocker(&mut self, position: Vec3, radius: f32) {
+        for index in self.cells_in_radius(position, radius) {
+            self.blockers[index] = self.blockers[index].saturating_sub(1);
+        }
+        self.version += 1;
+    }
+
+    // Closest passable cell to `cell`, searching outward ring by ring
+    fn nearest_open(&self, cell: IVec2) -> Option<IVec2> {
+        if !self.is_blocked(cell) {
+            return Some(cell);
+        }
+        for ring in 1..self.width.max(self.height) {
+            let mut best: Option<(i32, IVec2)> = None;
+            for dy in -ring..=ring {
+                for dx in -ring..=ring {
+                    if dx.abs() != ring && dy.abs() != ring {
+                        continue;
+                    }
+                    let candidate = cell + IVec2::new(dx, dy);
+                    if !self.is_blocked(candidate) {
+                        let distance = dx * dx + dy * dy;
+                        if best.is_none_or(|(d, _)| distance < d) {
+                            best = Some((distance, candidate));
+                        }
+                    }
+                }
+            }
+            if let Some((_, found)) = best {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    // Octile distance priced at highway steps, rounded down the same way the
+    // search prices them, so it never overestimates and stays admissible
+    fn heuristic(a: IVec2, b: IVec2) -> u32 {
+        let d = (a - b).abs();
+        let (long, short) = (d.x.max(d.y) as u32, d.x.min(d.y) as u32);
+        let straight = STRAIGHT_COST * HIGHWAY_COST / STRAIGHT_COST;
+        let diagonal = DIAGONAL_COST * HIGHWAY_COST / STRAIGHT_COST;
+        straight * (long - short) + diagonal * short
+    }
+
+    // A* from `start` to `goal`, returning smoothed world waypoints (excluding the start).
+    // The start cell may be blocked (a unit standing next to a fresh roadblock);
+    // a blocked goal is replaced by the nearest open cell. None means unreachable.
+    pub fn find_path(&self, start: Vec3, goal: Vec3) -> Option<Vec<Vec3>> {
+        let start_cell = self.world_to_cell(start);
+        let goal_cell = self.nearest_open(self.world_to_cell(goal))?;
+
+        if start_cell == goal_cell {
+            return Some(vec![goal]);
+        }
+
+        let (cells_path, _) = self.cell_path(start_cell, goal_cell)?;
+        let mut waypoints = self.smooth(start, &cells_path);
+        // Finish exactly on the requested goal when it is reachable
+        if goal_cell == self.world_to_cell(goal) {
+            if let Some(last) = waypoints.last_mut() {
+                *last = goal;
+            }
+        }
+        Some(waypoints)
+    }
+
+    // Cheapest run of cells from `start_cell` (excluded) to `goal_cell`, and its cost
+    fn cell_path(&self, start_cell: IVec2, goal_cell: IVec2) -> Option<(Vec<IVec2>, u32)> {
+        let cells = (self.width * self.height) as usize;
+        let mut g_score = vec![u32::MAX; cells];
+        let mut came_from: HashMap<usize, usize> = HashMap::new();
+        let mut open = BinaryHeap::new();
+
+        let start_index = self.index(start_cell);
+        let goal_index = self.index(goal_cell);
+        g_score[start_index] = 0;
+        open.push(OpenNode { f: Self::heuristic(start_cell, goal_cell), g: 0, index: start_index });
+
+        while let Some(OpenNode { g, index, .. }) = open.pop() {
+            if index == goal_index {
+                break;
+            }
+            if g > g_score[index] {
+                continue;
+            }
+            let cell = IVec2::new(index as i32 % self.width, index as i32 / self.width);
+
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let next = cell + IVec2::new(dx, dy);
+                    if self.is_blocked(next) {
+                        continue;
+                    }
+                    let diagonal = dx != 0 && dy != 0;
+                    // No corner cutting past blocked cells
+                    if diagonal
+                        && (self.is_blocked(cell + IVec2::new(dx, 0)) || self.is_blocked(cell + IVec2::new(0, dy)))
+                    {
+                        continue;
+                    }
+
+                    let next_index = self.index(next);
+                    let step = if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+                    let tentative = g + step * self.cost[next_index] / STRAIGHT_COST;
+                    if tentative < g_score[next_index] {
+                        g_score[next_index] = tentative;
+                        came_from.insert(next_index, index);
+                        open.push(OpenNode {
+                            f: tentative + Self::heuristic(next, goal_cell),
+                            g: tentative,
+                            index: next_index,
+                        });
+                    }
+                }
+            }
+        }
+
+        if g_score[goal_index] == u32::MAX {
+            return None;
+        }
+
+        let mut cells_path = vec![goal_cell];
+        let mut current = goal_index;
+        while let Some(&previous) = came_from.get(&current) {
+            if previous == start_index {
+                break;
+            }
+            cells_path.push(IVec2::new(previous as i32 % self.width, previous as i32 / self.width));
+            current = previous;
+        }
+        cells_path.reverse();
+        Some((cells_path, g_score[goal_index]))
+    }
+
+    // Drops waypoints that can be skipped in a straight, unobstructed line
+    fn smooth(&self, start: Vec3, cells: &[IVec2]) -> Vec<Vec3> {
+        let points: Vec<Vec3> = cells.iter().map(|cell| self.cell_center(*cell)).collect();
+        let mut waypoints = Vec::new();
+        let mut anchor = start.truncate().extend(0.0);
+        let mut i = 0;
+        while i < points.len() {
+            let mut furthest = i;
+            for (j, point) in points.iter().enumerate().skip(i + 1) {
+                if self.line_clear(anchor, *point) {
+                    furthest = j;
+                } else {
+                    break;
+                }
+            }
+            waypoints.push(points[furthest]);
+            anchor = points[furthest];
+            i = furthest + 1;
+        }
+        waypoints
+    }
+
+    fn line_clear(&self, from: Vec3, to: Vec3) -> bool {
+        let steps = (from.distance(to) / (NAV_CELL_SIZE * 0.25)).ceil().max(1.0) as i32;
+        (0..=steps).all(|step| {
+            let point = from.lerp(to, step as f32 / steps as f32);
+            let cell = self.world_to_cell(point);
+            cell == self.world_to_cell(from) || !self.is_blocked(cell)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Reverse;
+
+    // A bare map `width` x `height` map units with the given zones and obstacles
+    fn layout(width: f32, height: f32, zones: &str, obstacles: &str) -> MapLayout {
+        let json = format!(
+            r#"{{
+                "map_info": {{ "name": "test", "bounds": {{ "width": {}, "height": {} }} }},
+                "zones": [{}],
+                "strategic_points": [],
+                "obstacles": [{}],
+                "spawn_points": {{ "cartel": [], "military": [], "ovidio": {{ "position": [0, 0] }} }}
+            }}"#,
+            width, height, zones, obstacles
+        );
+        serde_json::from_str(&json).expect("test layout must parse")
+    }
+
+    // A wall down the middle of a 200x200 map, open only along the bottom three rows
+    fn walled_grid() -> NavGrid {
+        NavGrid::from_layout(&layout(200.0, 200.0, "", r#"{ "position": [100, 70], "size": [20, 140] }"#))
+    }
+
+    fn zone(kind: &str, position: [f32; 2], size: [f32; 2], density: f32) -> String {
+        format!(
+            r#"{{ "id": "{0}", "name": "{0}", "type": "{0}", "position": [{1}, {2}], "size": [{3}, {4}],
+                 "initial_control": "Civilian", "strategic_value": 0, "civilian_density": {5}, "infrastructure_level": 0 }}"#,
+            kind, position[0], position[1], size[0], size[1], density
+        )
+    }
+
+    // Plain Dijkstra over the same moves and prices as the A* search
+    fn cheapest_cost(grid: &NavGrid, start: IVec2, goal: IVec2) -> Option<u32> {
+        let mut best = vec![u32::MAX; (grid.width * grid.height) as usize];
+        let mut open = BinaryHeap::new();
+        best[grid.index(start)] = 0;
+        open.push(Reverse((0, start.x, start.y)));
+        while let Some(Reverse((cost, x, y))) = open.pop() {
+            let cell = IVec2::new(x, y);
+            if cell == goal {
+                return Some(cost);
+            }
+            if cost > best[grid.index(cell)] {
+                continue;
+            }
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let next = cell + IVec2::new(dx, dy);
+                    let diagonal = dx != 0 && dy != 0;
+                    if (dx, dy) == (0, 0)
+                        || grid.is_blocked(next)
+                        || diagonal && (grid.is_blocked(cell + IVec2::new(dx, 0)) || grid.is_blocked(cell + IVec2::new(0, dy)))
+                    {
+                        continue;
+                    }
+                    let step = if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+                    let next_cost = cost + step * grid.cost[grid.index(next)] / STRAIGHT_COST;
+                    if next_cost < best[grid.index(next)] {
+                        best[grid.index(next)] = next_cost;
+                        open.push(Reverse((next_cost, next.x, next.y)));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn paths_go_around_static_obstacles() {
+        let grid = walled_grid();
+        let start = Vec3::new(-80.0, 50.0, 0.0);
+        let goal = Vec3::new(80.0, 50.0, 0.0);
+        assert!(!grid.line_clear(start, goal));
+
+        let path = grid.find_path(start, goal).expect("the wall has a gap");
+        assert_eq!(path.last(), Some(&goal));
+        let mut from = start;
+        for waypoint in &path {
+            assert!(grid.line_clear(from, *waypoint), "{:?} -> {:?} crosses the wall", from, waypoint);
+            from = *waypoint;
+        }
+        assert!(path.iter().any(|waypoint| waypoint.y < -40.0), "the path never goes through the gap");
+    }
+
+    #[test]
+    fn a_wall_across_the_map_makes_the_goal_unreachable() {
+        let grid = NavGrid::from_layout(&layout(200.0, 200.0, "", r#"{ "position": [100, 100], "size": [20, 200] }"#));
+        assert_eq!(grid.find_path(Vec3::new(-80.0, 0.0, 0.0), Vec3::new(80.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn a_blocked_goal_is_replaced_by_the_nearest_open_cell() {
+        let grid = walled_grid();
+        let goal = Vec3::new(0.0, 50.0, 0.0);
+        assert!(grid.is_blocked(grid.world_to_cell(goal)));
+
+        let path = grid.find_path(Vec3::new(-80.0, 50.0, 0.0), goal).expect("the cells next to the wall are open");
+        assert_eq!(path.last(), Some(&Vec3::new(30.0, 50.0, 0.0)));
+    }
+
+    #[test]
+    fn roadblocks_block_their_cells_until_removed() {
+        let mut grid = NavGrid::from_layout(&layout(200.0, 200.0, "", ""));
+        let position = Vec3::new(0.0, 0.0, 0.0);
+        let cell = grid.world_to_cell(position);
+        assert!(!grid.is_blocked(cell));
+
+        grid.add_blocker(position, ROADBLOCK_BLOCK_RADIUS);
+        assert!(grid.is_blocked(cell));
+        assert_eq!(grid.version, 1);
+        assert!(!grid.line_clear(Vec3::new(-50.0, 0.0, 0.0), Vec3::new(50.0, 0.0, 0.0)));
+
+        grid.remove_blocker(position, ROADBLOCK_BLOCK_RADIUS);
+        assert!(!grid.is_blocked(cell));
+        assert_eq!(grid.version, 2);
+    }
+
+    #[test]
+    fn paths_are_optimal_with_highways() {
+        // Dense city everywhere but a highway along the top two rows
+        let zones = [
+            zone("CityCenter", [200.0, 100.0], [400.0, 200.0], 1.0),
+            zone("Highway", [200.0, 20.0], [400.0, 40.0], 0.0),
+        ];
+        let grid = NavGrid::from_layout(&layout(400.0, 200.0, &zones.join(","), ""));
+        let cells: Vec<IVec2> = [(0, 0), (0, 5), (19, 5), (10, 9), (19, 0), (7, 3)]
+            .into_iter()
+            .map(|(x, y)| IVec2::new(x, y))
+            .collect();
+
+        for &start in &cells {
+            for &goal in cells.iter().filter(|goal| **goal != start) {
+                let (path, cost) = grid.cell_path(start, goal).expect("the map is open");
+                assert_eq!(Some(cost), cheapest_cost(&grid, start, goal), "{:?} -> {:?}", start, goal);
+                assert!(NavGrid::heuristic(start, goal) <= cost);
+                assert_eq!(path.last(), Some(&goal));
+            }
+        }
+
+        // Crossing the city is cheaper along the highway than straight through
+        let (path, _) = grid.cell_path(IVec2::new(0, 5), IVec2::new(19, 5)).unwrap();
+        assert!(path.iter().any(|cell| cell.y >= 8));
+    }
+}
@@ -0,0 +1,61 @@
+// ==================== OVIDIO METADATA ====================
+// Typed model of assets/data/ovidio_metadata.json: how Ovidio can be taken
+// and moved out, and the political weight of holding him.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::data;
+
+pub const OVIDIO_METADATA_PATH: &str = "assets/data/ovidio_metadata.json";
+
+const BUILTIN_OVIDIO_METADATA: &str = include_str!("../assets/data/ovidio_metadata.json");
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct OvidioMetadata {
+    pub capture_mechanics: CaptureMechanics,
+    pub political_impact: PoliticalImpact,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CaptureMechanics {
+    // Distance at which soldiers can take an unprotected Ovidio into custody
+    pub capture_range: f32,
+    // Seconds of escorting needed to get him to the extraction point
+    pub extraction_time: f32,
+    // Cartel guards that must stay close to keep him from being taken
+    pub protection_detail: u32,
+    pub escape_routes: Vec<EscapeRoute>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EscapeRoute {
+    pub route_id: String,
+    // 0..1; scales how long Ovidio must hold at the end of the route
+    pub difficulty: f32,
+    // Map zone or strategic point id the route leads to (defaults to route_id)
+    #[serde(default)]
+    pub destination: Option<String>,
+}
+
+impl EscapeRoute {
+    pub fn destination_id(&self) -> &str {
+        self.destination.as_deref().unwrap_or(&self.route_id)
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct PoliticalImpact {
+    // Scales every source of pressure
+    pub base_pressure_value: f32,
+    // Extra weight of harm that makes the news (civilian casualties)
+    pub media_multiplier: f32,
+    // Fraction added on top of all pressure for foreign attention
+    pub international_concern: f32,
+    // Pressure at which the government releases Ovidio
+    pub release_threshold: f32,
+}
+
+pub fn load_ovidio_metadata_or_builtin(path: &str) -> OvidioMetadata {
+    data::load_json_or_builtin(path, BUILTIN_OVIDIO_METADATA, data::no_problems)
+}
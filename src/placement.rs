@@ -0,0 +1,147 @@
+// ==================== ROADBLOCK PLACEMENT ====================
+// SPACE enters placement mode: a ghost roadblock follows the cursor, snaps
+// onto nearby roads and lines up with them, and turns red where it cannot be
+// built. Left-click builds it, right-click or SPACE again cancels.
+
+use bevy::prelude::*;
+
+use crate::map::MapLayout;
+use crate::nav::NavGrid;
+use crate::selection::PlayerInput;
+use crate::sim::{PlayerCommand, PlayerCommands, PlayerSide};
+use crate::unit_stats::UnitRegistry;
+use crate::{iso_rotation, iso_to_world, world_to_iso, Faction, Unit, WorldPosition};
+
+// Cursor positions this close to a road snap onto it
+const ROAD_SNAP_DISTANCE: f32 = 40.0;
+// No building under the noses of soldiers
+const ENEMY_CLEARANCE: f32 = 100.0;
+
+#[derive(Resource, Default)]
+pub struct RoadblockPlacement {
+    pub active: bool,
+}
+
+#[derive(Component)]
+pub struct RoadblockGhost;
+
+// Closest point on any road to `position`, with the road's heading there
+pub fn snap_to_road(segments: &[(Vec3, Vec3)], position: Vec3) -> Option<(Vec3, f32)> {
+    segments.iter()
+        .filter_map(|&(a, b)| {
+            let ab = b - a;
+            let length_squared = ab.length_squared();
+            if length_squared == 0.0 {
+                return None;
+            }
+            let t = ((position - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+            let point = a + ab * t;
+            Some((point, ab.y.atan2(ab.x), point.distance(position)))
+        })
+        .filter(|(_, _, distance)| *distance <= ROAD_SNAP_DISTANCE)
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(point, heading, _)| (point, heading))
+}
+
+// Where a roadblock dropped at `position` ends up: across a nearby road, or
+// right there facing the default way
+pub fn roadblock_site(segments: &[(Vec3, Vec3)], position: Vec3) -> (Vec3, f32) {
+    match snap_to_road(segments, position) {
+        // Roadblocks stand across the road
+        Some((point, heading)) => (point, heading + std::f32::consts::FRAC_PI_2),
+        None => (position, 0.0),
+    }
+}
+
+// Why a roadblock cannot go at `position`, if anything stops it
+pub fn placement_problem<'a>(
+    grid: &NavGrid,
+    units: impl Iterator<Item = (&'a Unit, &'a WorldPosition)>,
+    position: Vec3,
+) -> Option<&'static str> {
+    if !grid.contains(position) {
+        return Some("outside the city");
+    }
+    if grid.is_blocked(grid.world_to_cell(position)) {
+        return Some("blocked by water, a building or another roadblock");
+    }
+    let mut units = units;
+    if units.any(|(unit, other)| unit.faction == Faction::Military && other.0.distance(position) <= ENEMY_CLEARANCE) {
+        return Some("too close to government forces");
+    }
+    None
+}
+
+pub fn spawn_roadblock_ghost(mut commands: Commands, registry: Res<UnitRegistry>) {
+    let stats = registry.get(&crate::UnitType::Roadblock);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.3, 1.0, 0.3, 0.5),
+                custom_size: Some(stats.size()),
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        RoadblockGhost,
+    ));
+}
+
+pub fn placement_system(
+    input: PlayerInput,
+    layout: Res<MapLayout>,
+    grid: Res<NavGrid>,
+    mut placement: ResMut<RoadblockPlacement>,
+    mut player_commands: ResMut<PlayerCommands>,
+    unit_query: Query<(&Unit, &WorldPosition)>,
+    mut ghost_query: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<RoadblockGhost>>,
+) {
+    let Ok((mut transform, mut sprite, mut visibility)) = ghost_query.get_single_mut() else {
+        return;
+    };
+
+    // Only the cartel builds roadblocks
+    if *input.side != PlayerSide::Cartel {
+        placement.active = false;
+    } else if input.keys.just_pressed(KeyCode::Space) {
+        placement.active = !placement.active;
+        if placement.active {
+            info!("🚧 Placement mode: LEFT CLICK to build, RIGHT CLICK to cancel");
+        }
+    } else if placement.active && input.mouse.just_pressed(MouseButton::Right) {
+        placement.active = false;
+    }
+
+    if !placement.active {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let Some(cursor) = input.cursor() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    // The cursor is in projected screen space; roadblocks go on the map
+    let (position, heading) = roadblock_site(&layout.road_segments(), iso_to_world(cursor.extend(0.0)));
+    let problem = placement_problem(&grid, unit_query.iter(), position);
+
+    *visibility = Visibility::Visible;
+    transform.translation = world_to_iso(position) + Vec3::new(0.0, 0.0, 5.0);
+    transform.rotation = iso_rotation(heading);
+    sprite.color = match problem {
+        Some(_) => Color::rgba(1.0, 0.2, 0.2, 0.5),
+        None => Color::rgba(0.3, 1.0, 0.3, 0.5),
+    };
+
+    if input.mouse.just_pressed(MouseButton::Left) {
+        match problem {
+            Some(problem) => warn!("🚧 Cannot build here: {}", problem),
+            None => {
+                player_commands.push(PlayerCommand::DeployRoadblock { position, heading });
+                placement.active = false;
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// ==================== POLITICAL PRESSURE ====================
+// The cartel wins by making Ovidio too costly to hold. Roadblocks, dead
+// soldiers, civilian harm and held strategic points build pressure on the
+// government, weighted by the political_impact block of the Ovidio metadata.
+// Once pressure reaches release_threshold, the government releases him.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ovidio::OvidioMetadata;
+use crate::strategic::StrategicPoint;
+use crate::{Faction, GameState, MatchStats, Unit, UnitType};
+
+// Per standing roadblock, per second
+const ROADBLOCK_PRESSURE: f32 = 0.0004;
+// Per point of importance of a cartel-held strategic point, per second
+const HELD_POINT_PRESSURE: f32 = 0.0006;
+// Per soldier killed
+const MILITARY_CASUALTY_PRESSURE: f32 = 0.02;
+// Per civilian killed, before the media multiplier
+const CIVILIAN_CASUALTY_PRESSURE: f32 = 0.03;
+
+#[derive(Resource, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct PoliticalPressure {
+    pub value: f32,
+    // Losses already turned into pressure
+    pub military_losses_counted: u32,
+    pub civilian_casualties_counted: u32,
+    // Last quarter of the release threshold announced in the log
+    pub quarters_reported: u32,
+}
+
+impl PoliticalPressure {
+    // Share of the release threshold reached, 0..=1
+    pub fn progress(&self, metadata: &OvidioMetadata) -> f32 {
+        (self.value / metadata.political_impact.release_threshold).clamp(0.0, 1.0)
+    }
+
+    pub fn government_breaks(&self, metadata: &OvidioMetadata) -> bool {
+        self.value >= metadata.political_impact.release_threshold
+    }
+}
+
+pub fn is_military_unit(unit_type: &UnitType) -> bool {
+    matches!(unit_type, UnitType::Soldier | UnitType::SpecialForces | UnitType::Vehicle)
+}
+
+pub fn political_pressure_system(
+    time: Res<Time>,
+    metadata: Res<OvidioMetadata>,
+    game_state: Res<GameState>,
+    stats: Res<MatchStats>,
+    mut pressure: ResMut<PoliticalPressure>,
+    unit_query: Query<&Unit>,
+    point_query: Query<&StrategicPoint>,
+) {
+    let impact = metadata.political_impact;
+
+    let roadblocks = unit_query.iter()
+        .filter(|unit| unit.unit_type == UnitType::Roadblock)
+        .count() as f32;
+
+    let held_importance: f32 = point_query.iter()
+        .filter(|point| point.owned_by(&Faction::Cartel))
+        .map(|point| point.importance)
+        .sum();
+
+    let military_losses: u32 = stats.units_lost.iter()
+        .filter(|(unit_type, _)| is_military_unit(unit_type))
+        .map(|(_, count)| count)
+        .sum();
+    let civilian_casualties = game_state.civilian_casualties.total();
+    let new_military_losses = military_losses.saturating_sub(pressure.military_losses_counted);
+    let new_civilian_casualties = civilian_casualties.saturating_sub(pressure.civilian_casualties_counted);
+    pressure.military_losses_counted = military_losses;
+    pressure.civilian_casualties_counted = civilian_casualties;
+
+    let sustained = (roadblocks * ROADBLOCK_PRESSURE + held_importance * HELD_POINT_PRESSURE) * time.delta_seconds();
+    let casualties = new_military_losses as f32 * MILITARY_CASUALTY_PRESSURE
+        + new_civilian_casualties as f32 * CIVILIAN_CASUALTY_PRESSURE * impact.media_multiplier;
+    pressure.value += (sustained + casualties) * impact.base_pressure_value * (1.0 + impact.international_concern);
+
+    let quarters = (pressure.progress(&metadata) * 4.0) as u32;
+    if quarters > pressure.quarters_reported {
+        pressure.quarters_reported = quarters;
+        match quarters {
+            1 => info!("📰 Local media report gunfire across Culiacán - pressure on the government rises"),
+            2 => info!("📺 National networks broadcast the chaos live - the cabinet is alarmed"),
+            3 => info!("🌎 International outlets pick up the story - officials debate releasing Ovidio"),
+            _ => info!("🏛️ The government can no longer hold: the order to release Ovidio is given"),
+        }
+    }
+}
+
+// ==================== HUD ====================
+
+#[derive(Component)]
+pub struct PressureText;
+
+#[derive(Component)]
+pub struct PressureBar;
+
+pub fn spawn_pressure_meter(parent: &mut ChildBuilder) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "🏛️ Political Pressure: 0%",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::rgb(1.0, 0.8, 0.4),
+                        ..default()
+                    },
+                ),
+                PressureText,
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(200.0),
+                        height: Val::Px(10.0),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.25, 0.25, 0.25).into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: Color::rgb(1.0, 0.6, 0.1).into(),
+                            ..default()
+                        },
+                        PressureBar,
+                    ));
+                });
+        });
+}
+
+pub fn pressure_hud_system(
+    metadata: Res<OvidioMetadata>,
+    pressure: Res<PoliticalPressure>,
+    mut text_query: Query<&mut Text, With<PressureText>>,
+    mut bar_query: Query<&mut Style, With<PressureBar>>,
+) {
+    let progress = pressure.progress(&metadata);
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("🏛️ Political Pressure: {:.0}%", progress * 100.0);
+    }
+    if let Ok(mut style) = bar_query.get_single_mut() {
+        style.width = Val::Percent(progress * 100.0);
+    }
+}
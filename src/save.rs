@@ -0,0 +1,549 @@
+// ==================== SAVE & LOAD ====================
+// Snapshots a running match to JSON and restores it at the start of a fixed
+// tick. F5 quicksaves, F9 quickloads and `--load <path>` starts from a save.
+// Entity references are written as save-local unit ids and re-linked on load.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use crate::capture::OvidioStatus;
+use crate::civilians::{spawn_civilian, Civilian, CivilianCasualties};
+use crate::commander::CartelCommander;
+use crate::economy::CartelResources;
+use crate::map::{MapLayout, Zone};
+use crate::mission::MissionProgress;
+use crate::nav::{NavGrid, NavPath, ROADBLOCK_BLOCK_RADIUS};
+use crate::pressure::PoliticalPressure;
+use crate::selection::AttackOrder;
+use crate::sim::{RngState, SimRng};
+use crate::squads::{Squad, SquadMember, SquadObjective, Squads};
+use crate::strategic::{PointControl, StrategicPoint};
+use crate::unit_stats::UnitRegistry;
+use crate::vehicles::{ConvoySlot, Vehicle};
+use crate::zones::ZoneControl;
+use crate::{
+    spawn_ovidio, spawn_roadblock, spawn_unit, Facing, Faction, GamePhase, GameState, HealthBar, MatchStats, Movement,
+    Objective, ObjectiveType, Unit, UnitType, WaveSpawner, WorldPosition,
+};
+
+pub const QUICKSAVE_PATH: &str = "saves/quicksave.json";
+
+// Bump whenever the save schema changes: older saves are refused rather than
+// loaded with missing state filled in by defaults
+const SAVE_VERSION: u32 = 4;
+
+// Save and load requests, applied by the next fixed tick
+#[derive(Resource, Default)]
+pub struct SaveRequests {
+    pub save: Option<String>,
+    pub load: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    version: u32,
+    // Exact position in the simulation RNG stream, so a loaded match continues
+    // exactly like the one that was saved; saving leaves the stream alone
+    rng: RngState,
+    // Nav grid version, so cached unit paths stay valid after loading
+    nav_version: u32,
+    game_state: SavedGameState,
+    wave_spawner: Option<SavedWaveSpawner>,
+    objectives: Vec<SavedObjective>,
+    units: Vec<SavedUnit>,
+    civilians: Vec<SavedCivilian>,
+    political_pressure: PoliticalPressure,
+    cartel_resources: CartelResources,
+    // Zone owners by zone id
+    zones: HashMap<String, ZoneControl>,
+    squads: Vec<SavedSquad>,
+    next_squad_id: u32,
+    cartel_commander: CartelCommander,
+    units_lost: HashMap<UnitType, u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedGameState {
+    mission_timer: f32,
+    current_wave: u32,
+    cartel_score: u32,
+    military_score: u32,
+    game_phase: GamePhase,
+    ovidio_status: OvidioStatus,
+    civilian_casualties: CivilianCasualties,
+    mission: MissionProgress,
+}
+
+// Seconds as f64 so the nanosecond timer state survives the round trip;
+// a rounded elapsed time would shift when cooldowns fire
+#[derive(Serialize, Deserialize)]
+struct SavedTimer {
+    duration: f64,
+    elapsed: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedWaveSpawner {
+    next_wave_timer: SavedTimer,
+    wave_number: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedObjective {
+    objective_type: ObjectiveType,
+    position: [f32; 3],
+    health: f32,
+    control: Option<PointControl>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedUnit {
+    id: u32,
+    unit_type: UnitType,
+    faction: Faction,
+    position: [f32; 3],
+    health: f32,
+    max_health: f32,
+    damage: f32,
+    range: f32,
+    movement_speed: f32,
+    target: Option<u32>,
+    attack_cooldown: SavedTimer,
+    move_target: Option<[f32; 3]>,
+    attack_order: bool,
+    path: Option<SavedPath>,
+    heading: Option<f32>,
+    squad: Option<SquadMember>,
+    vehicle: Option<SavedVehicle>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedVehicle {
+    turret: f32,
+    passengers: Vec<UnitType>,
+    convoy: Option<ConvoySlot>,
+    route: Vec<[f32; 3]>,
+    route_goal: Option<[f32; 3]>,
+    blocked: bool,
+    on_target: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedSquad {
+    id: u32,
+    objective: SquadObjective,
+    rally: [f32; 3],
+    clearing_roadblock: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedPath {
+    waypoints: Vec<[f32; 3]>,
+    goal: Option<[f32; 3]>,
+    grid_version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedCivilian {
+    position: [f32; 3],
+    health: f32,
+    home_min: [f32; 2],
+    home_max: [f32; 2],
+    destination: Option<[f32; 3]>,
+    panic: f32,
+    flee_from: [f32; 3],
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io { path: String, source: std::io::Error },
+    Parse { path: String, source: serde_json::Error },
+    Version { path: String, found: u32 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io { path, source } => write!(f, "cannot access save '{}': {}", path, source),
+            SaveError::Parse { path, source } => write!(f, "invalid save '{}': {}", path, source),
+            SaveError::Version { path, found } => write!(
+                f,
+                "save '{}' has version {} (expected {})",
+                path, found, SAVE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl SavedTimer {
+    fn from_timer(timer: &Timer) -> Self {
+        Self {
+            duration: timer.duration().as_secs_f64(),
+            elapsed: timer.elapsed().as_secs_f64(),
+        }
+    }
+
+    fn to_timer(&self) -> Timer {
+        let mut timer = Timer::new(Duration::from_secs_f64(self.duration), TimerMode::Repeating);
+        timer.set_elapsed(Duration::from_secs_f64(self.elapsed));
+        timer
+    }
+}
+
+fn write_save(path: &str, save: &SaveFile) -> Result<(), SaveError> {
+    let io_error = |source| SaveError::Io { path: path.to_string(), source };
+    if let Some(dir) = std::path::Path::new(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let contents = serde_json::to_string_pretty(save).expect("save file serializes");
+    std::fs::write(path, contents).map_err(io_error)
+}
+
+fn read_save(path: &str) -> Result<SaveFile, SaveError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| SaveError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    let save: SaveFile = serde_json::from_str(&contents).map_err(|source| SaveError::Parse {
+        path: path.to_string(),
+        source,
+    })?;
+    if save.version != SAVE_VERSION {
+        return Err(SaveError::Version { path: path.to_string(), found: save.version });
+    }
+    Ok(save)
+}
+
+// ==================== SYSTEMS ====================
+
+// Match-wide state a save records besides the entities
+#[derive(SystemParam)]
+pub struct MatchResources<'w> {
+    game_state: Res<'w, GameState>,
+    stats: Res<'w, MatchStats>,
+    pressure: Res<'w, PoliticalPressure>,
+    resources: Res<'w, CartelResources>,
+    squads: Res<'w, Squads>,
+    commander: Res<'w, CartelCommander>,
+    rng: Res<'w, SimRng>,
+    grid: Res<'w, NavGrid>,
+}
+
+// The same state for a load to overwrite, plus what it needs to respawn units
+#[derive(SystemParam)]
+pub struct MatchResourcesMut<'w> {
+    registry: Res<'w, UnitRegistry>,
+    layout: Res<'w, MapLayout>,
+    game_state: ResMut<'w, GameState>,
+    stats: ResMut<'w, MatchStats>,
+    pressure: ResMut<'w, PoliticalPressure>,
+    resources: ResMut<'w, CartelResources>,
+    squads: ResMut<'w, Squads>,
+    commander: ResMut<'w, CartelCommander>,
+    rng: ResMut<'w, SimRng>,
+    grid: ResMut<'w, NavGrid>,
+}
+
+type SavedUnitData = (
+    Entity,
+    &'static Unit,
+    &'static WorldPosition,
+    Option<&'static Movement>,
+    Option<&'static AttackOrder>,
+    Option<&'static NavPath>,
+    Option<&'static Facing>,
+    Option<&'static SquadMember>,
+    Option<&'static Vehicle>,
+);
+
+// Everything a load replaces
+type MatchEntityFilter = Or<(With<Unit>, With<HealthBar>, With<Civilian>)>;
+
+pub fn save_match_system(
+    mut requests: ResMut<SaveRequests>,
+    state: MatchResources,
+    unit_query: Query<SavedUnitData>,
+    civilian_query: Query<(&Civilian, &WorldPosition)>,
+    wave_query: Query<&WaveSpawner>,
+    objective_query: Query<(&Objective, Option<&StrategicPoint>)>,
+    zone_query: Query<(&Zone, &ZoneControl)>,
+) {
+    let Some(path) = requests.save.take() else {
+        return;
+    };
+    let MatchResources { game_state, stats, pressure, resources, squads, commander, rng, grid } = state;
+
+    let ids: HashMap<Entity, u32> = unit_query.iter()
+        .enumerate()
+        .map(|(id, (entity, ..))| (entity, id as u32))
+        .collect();
+
+    let units = unit_query.iter()
+        .map(|(entity, unit, position, movement, attack_order, path, facing, member, vehicle)| SavedUnit {
+            id: ids[&entity],
+            unit_type: unit.unit_type.clone(),
+            faction: unit.faction.clone(),
+            position: position.0.to_array(),
+            health: unit.health,
+            max_health: unit.max_health,
+            damage: unit.damage,
+            range: unit.range,
+            movement_speed: unit.movement_speed,
+            // Combat clears targets as they die, so every target is still here
+            target: unit.target.and_then(|target| ids.get(&target).copied()),
+            attack_cooldown: SavedTimer::from_timer(&unit.attack_cooldown),
+            move_target: movement.and_then(|movement| movement.target_position).map(|target| target.to_array()),
+            attack_order: attack_order.is_some(),
+            path: path.map(|path| SavedPath {
+                waypoints: path.waypoints.iter().map(|waypoint| waypoint.to_array()).collect(),
+                goal: path.goal.map(|goal| goal.to_array()),
+                grid_version: path.grid_version,
+            }),
+            heading: facing.map(|facing| facing.0),
+            squad: member.cloned(),
+            vehicle: vehicle.map(|vehicle| SavedVehicle {
+                turret: vehicle.turret,
+                passengers: vehicle.passengers.clone(),
+                convoy: vehicle.convoy,
+                route: vehicle.route.iter().map(|waypoint| waypoint.to_array()).collect(),
+                route_goal: vehicle.route_goal.map(|goal| goal.to_array()),
+                blocked: vehicle.blocked,
+                on_target: vehicle.on_target,
+            }),
+        })
+        .collect();
+
+    let save = SaveFile {
+        version: SAVE_VERSION,
+        rng: rng.state(),
+        nav_version: grid.version,
+        game_state: SavedGameState {
+            mission_timer: game_state.mission_timer,
+            current_wave: game_state.current_wave,
+            cartel_score: game_state.cartel_score,
+            military_score: game_state.military_score,
+            game_phase: game_state.game_phase.clone(),
+            ovidio_status: game_state.ovidio_status.clone(),
+            civilian_casualties: game_state.civilian_casualties,
+            mission: game_state.mission.clone(),
+        },
+        wave_spawner: wave_query.iter().next().map(|spawner| SavedWaveSpawner {
+            next_wave_timer: SavedTimer::from_timer(&spawner.next_wave_timer),
+            wave_number: spawner.wave_number,
+        }),
+        objectives: objective_query.iter()
+            .map(|(objective, point)| SavedObjective {
+                objective_type: objective.objective_type.clone(),
+                position: objective.position.to_array(),
+                health: objective.health,
+                control: point.map(|point| point.control.clone()),
+            })
+            .collect(),
+        units,
+        civilians: civilian_query.iter()
+            .map(|(civilian, position)| SavedCivilian {
+                position: position.0.to_array(),
+                health: civilian.health,
+                home_min: civilian.home_min.to_array(),
+                home_max: civilian.home_max.to_array(),
+                destination: civilian.destination.map(|destination| destination.to_array()),
+                panic: civilian.panic,
+                flee_from: civilian.flee_from.to_array(),
+            })
+            .collect(),
+        units_lost: stats.units_lost.clone(),
+        political_pressure: pressure.clone(),
+        cartel_resources: resources.clone(),
+        zones: zone_query.iter()
+            .map(|(zone, control)| (zone.id.clone(), control.clone()))
+            .collect(),
+        squads: squads.squads.iter()
+            .map(|(&id, squad)| SavedSquad {
+                id,
+                objective: squad.objective.clone(),
+                rally: squad.rally.to_array(),
+                clearing_roadblock: squad.clearing_roadblock,
+            })
+            .collect(),
+        next_squad_id: squads.next_id,
+        cartel_commander: commander.clone(),
+    };
+
+    match write_save(&path, &save) {
+        Ok(()) => info!("💾 Match saved to {} ({:.0}s in)", path, game_state.mission_timer),
+        Err(err) => error!("❌ {}", err),
+    }
+}
+
+pub fn load_match_system(
+    mut commands: Commands,
+    mut requests: ResMut<SaveRequests>,
+    state: MatchResourcesMut,
+    match_query: Query<Entity, MatchEntityFilter>,
+    mut wave_query: Query<&mut WaveSpawner>,
+    mut objective_query: Query<(&mut Objective, Option<&mut StrategicPoint>)>,
+    mut zone_query: Query<(&Zone, &mut ZoneControl)>,
+) {
+    let Some(path) = requests.load.take() else {
+        return;
+    };
+    let save = match read_save(&path) {
+        Ok(save) => save,
+        Err(err) => {
+            error!("❌ {}", err);
+            warn!("💾 Keeping the current match");
+            return;
+        }
+    };
+    let MatchResourcesMut {
+        registry,
+        layout,
+        mut game_state,
+        mut stats,
+        mut pressure,
+        mut resources,
+        mut squads,
+        mut commander,
+        mut rng,
+        mut grid,
+    } = state;
+
+    // Clear the battlefield; labels are children and go with their units
+    for entity in match_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    *grid = NavGrid::from_layout(&layout);
+
+    // Spawn every unit first so targets can point at any of them
+    let mut entities = HashMap::new();
+    for saved in &save.units {
+        let position = Vec3::from_array(saved.position);
+        let entity = match saved.unit_type {
+            UnitType::Ovidio => spawn_ovidio(&mut commands, &registry, position),
+            UnitType::Roadblock => {
+                grid.add_blocker(position, ROADBLOCK_BLOCK_RADIUS);
+                spawn_roadblock(&mut commands, &registry, position, saved.heading.unwrap_or_default())
+            }
+            _ => spawn_unit(&mut commands, &registry, saved.unit_type.clone(), saved.faction.clone(), position),
+        };
+        entities.insert(saved.id, entity);
+    }
+
+    for saved in &save.units {
+        let entity = entities[&saved.id];
+        commands.entity(entity).insert(Unit {
+            health: saved.health,
+            max_health: saved.max_health,
+            faction: saved.faction.clone(),
+            unit_type: saved.unit_type.clone(),
+            damage: saved.damage,
+            range: saved.range,
+            movement_speed: saved.movement_speed,
+            target: saved.target.and_then(|id| entities.get(&id).copied()),
+            attack_cooldown: saved.attack_cooldown.to_timer(),
+        });
+        if saved.unit_type != UnitType::Roadblock {
+            commands.entity(entity).insert(Movement {
+                target_position: saved.move_target.map(Vec3::from_array),
+                speed: saved.movement_speed,
+            });
+        }
+        if let Some(path) = &saved.path {
+            commands.entity(entity).insert(NavPath {
+                waypoints: path.waypoints.iter().copied().map(Vec3::from_array).collect(),
+                goal: path.goal.map(Vec3::from_array),
+                grid_version: path.grid_version,
+            });
+        }
+        if saved.attack_order {
+            commands.entity(entity).insert(AttackOrder);
+        }
+        if let Some(member) = &saved.squad {
+            commands.entity(entity).insert(member.clone());
+        }
+        if let Some(vehicle) = &saved.vehicle {
+            commands.entity(entity).insert((
+                Vehicle {
+                    turret: vehicle.turret,
+                    passengers: vehicle.passengers.clone(),
+                    convoy: vehicle.convoy,
+                    route: vehicle.route.iter().copied().map(Vec3::from_array).collect(),
+                    route_goal: vehicle.route_goal.map(Vec3::from_array),
+                    blocked: vehicle.blocked,
+                    on_target: vehicle.on_target,
+                },
+                Facing(saved.heading.unwrap_or_default()),
+            ));
+        }
+    }
+
+    grid.version = save.nav_version;
+
+    for saved in &save.civilians {
+        let mut civilian = Civilian::new(Vec2::from_array(saved.home_min), Vec2::from_array(saved.home_max));
+        civilian.health = saved.health;
+        civilian.destination = saved.destination.map(Vec3::from_array);
+        civilian.panic = saved.panic;
+        civilian.flee_from = Vec3::from_array(saved.flee_from);
+        spawn_civilian(&mut commands, civilian, Vec3::from_array(saved.position));
+    }
+
+    if let (Some(saved), Ok(mut spawner)) = (&save.wave_spawner, wave_query.get_single_mut()) {
+        spawner.next_wave_timer = saved.next_wave_timer.to_timer();
+        spawner.wave_number = saved.wave_number;
+    }
+
+    // Objectives are static map features, matched back up by type and position
+    for (mut objective, point) in objective_query.iter_mut() {
+        if let Some(saved) = save.objectives.iter().find(|saved| {
+            saved.objective_type == objective.objective_type
+                && Vec3::from_array(saved.position).distance(objective.position) < 1.0
+        }) {
+            objective.health = saved.health;
+            if let (Some(mut point), Some(control)) = (point, &saved.control) {
+                point.control = control.clone();
+            }
+        }
+    }
+
+    for (zone, mut control) in zone_query.iter_mut() {
+        if let Some(saved) = save.zones.get(&zone.id) {
+            *control = saved.clone();
+        }
+    }
+
+    let saved_state = &save.game_state;
+    game_state.mission_timer = saved_state.mission_timer;
+    game_state.current_wave = saved_state.current_wave;
+    game_state.cartel_score = saved_state.cartel_score;
+    game_state.military_score = saved_state.military_score;
+    game_state.game_phase = saved_state.game_phase.clone();
+    game_state.ovidio_status = saved_state.ovidio_status.clone();
+    game_state.civilian_casualties = saved_state.civilian_casualties;
+    game_state.mission = saved_state.mission.clone();
+    game_state.scripted_outcome = None;
+    game_state.outcome = None;
+    stats.units_lost = save.units_lost;
+    *pressure = save.political_pressure;
+    *resources = save.cartel_resources;
+    *squads = Squads {
+        next_id: save.next_squad_id,
+        squads: save.squads.into_iter()
+            .map(|saved| (saved.id, Squad {
+                objective: saved.objective,
+                rally: Vec3::from_array(saved.rally),
+                clearing_roadblock: saved.clearing_roadblock,
+            }))
+            .collect(),
+    };
+    *commander = save.cartel_commander;
+    rng.restore(&save.rng);
+
+    info!("💾 Match loaded from {} ({:.0}s in, {} units)", path, game_state.mission_timer, save.units.len());
+}
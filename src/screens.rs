@@ -0,0 +1,327 @@
+// ==================== GAME STATES & SCREENS ====================
+// Top-level flow of the windowed game: main menu, mission briefing, the match
+// itself, pause and the results screen. The simulation only ticks InGame.
+
+use bevy::app::AppExit;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::difficulty::{Difficulty, DifficultyPresets};
+use crate::map::Zone;
+use crate::mission::MissionScript;
+use crate::ovidio::OvidioMetadata;
+use crate::pressure::PoliticalPressure;
+use crate::sim::PlayerSide;
+use crate::zones::{CityControl, ZoneControl};
+use crate::{GameState, MatchStats};
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppState {
+    #[default]
+    MainMenu,
+    Briefing,
+    InGame,
+    Paused,
+    Results,
+}
+
+pub struct ScreensPlugin;
+
+impl Plugin for ScreensPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::MainMenu), spawn_main_menu)
+            .add_systems(OnExit(AppState::MainMenu), despawn_screen::<MainMenuScreen>)
+            .add_systems(OnEnter(AppState::Briefing), spawn_briefing)
+            .add_systems(OnExit(AppState::Briefing), despawn_screen::<BriefingScreen>)
+            .add_systems(OnEnter(AppState::Paused), spawn_pause_screen)
+            .add_systems(OnExit(AppState::Paused), despawn_screen::<PauseScreen>)
+            .add_systems(OnEnter(AppState::Results), spawn_results_screen)
+            .add_systems(Update, (
+                main_menu_input.run_if(in_state(AppState::MainMenu)),
+                briefing_input.run_if(in_state(AppState::Briefing)),
+                // Redraw the briefing when another difficulty is picked
+                (despawn_screen::<BriefingScreen>, spawn_briefing)
+                    .chain()
+                    .run_if(in_state(AppState::Briefing).and_then(resource_changed::<Difficulty>())),
+                pause_input.run_if(in_state(AppState::InGame).or_else(in_state(AppState::Paused))),
+                match_end_system.run_if(in_state(AppState::InGame)),
+                results_input.run_if(in_state(AppState::Results)),
+            ));
+    }
+}
+
+#[derive(Component)]
+struct MainMenuScreen;
+
+#[derive(Component)]
+struct BriefingScreen;
+
+#[derive(Component)]
+struct PauseScreen;
+
+#[derive(Component)]
+struct ResultsScreen;
+
+fn despawn_screen<T: Component>(mut commands: Commands, screen_query: Query<Entity, With<T>>) {
+    for entity in screen_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Full-window overlay with one centered column of text lines
+fn spawn_overlay(commands: &mut Commands, marker: impl Component, background: Color, lines: Vec<(String, f32, Color)>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: background.into(),
+                z_index: ZIndex::Global(10),
+                ..default()
+            },
+            marker,
+        ))
+        .with_children(|parent| {
+            for (text, font_size, color) in lines {
+                parent.spawn(TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font_size,
+                        color,
+                        ..default()
+                    },
+                ));
+            }
+        });
+}
+
+// ==================== SCREENS ====================
+
+fn spawn_main_menu(mut commands: Commands) {
+    spawn_overlay(&mut commands, MainMenuScreen, Color::rgba(0.05, 0.05, 0.05, 0.95), vec![
+        ("⚔️ Battle of Culiacán".to_string(), 56.0, Color::rgb(1.0, 0.9, 0.6)),
+        ("El Culiacanazo - October 17, 2019".to_string(), 26.0, Color::rgb(0.9, 0.6, 0.4)),
+        (String::new(), 20.0, Color::WHITE),
+        ("C: Play the Cartel | M: Play the Military | V: Watch AI vs AI".to_string(), 22.0, Color::rgb(0.8, 0.8, 0.8)),
+        ("ENTER: Start Operation | ESC: Quit".to_string(), 22.0, Color::rgb(0.8, 0.8, 0.8)),
+    ]);
+}
+
+fn spawn_briefing(
+    mut commands: Commands,
+    side: Res<PlayerSide>,
+    script: Res<MissionScript>,
+    difficulty: Res<Difficulty>,
+    presets: Res<DifficultyPresets>,
+) {
+    let (orders, goal) = match *side {
+        PlayerSide::Cartel => (
+            "Hold the safehouse, block the convoy routes and make the cost of holding him too high.",
+            "Keep Ovidio alive until the government backs down.",
+        ),
+        PlayerSide::Military => (
+            "Push through the cartel roadblocks, reach the safehouse and hold him before he slips away.",
+            "Take Ovidio alive and get him out before the pressure forces a release.",
+        ),
+        PlayerSide::Spectator => (
+            "Both sides are under AI command. Watch the operation unfold.",
+            "F3 shows what every unit has decided to do.",
+        ),
+    };
+    spawn_overlay(&mut commands, BriefingScreen, Color::rgba(0.05, 0.05, 0.05, 0.9), vec![
+        ("📻 MISSION BRIEFING".to_string(), 40.0, Color::rgb(1.0, 0.9, 0.6)),
+        (format!("📋 {}", script.name), 24.0, Color::rgb(0.9, 0.6, 0.4)),
+        (script.description.clone(), 18.0, Color::rgb(0.8, 0.8, 0.8)),
+        ("Government forces have raided a house in Tres Ríos and detained Ovidio Guzmán López.".to_string(), 20.0, Color::WHITE),
+        (orders.to_string(), 20.0, Color::WHITE),
+        (goal.to_string(), 20.0, Color::rgb(1.0, 0.3, 0.3)),
+        (String::new(), 20.0, Color::WHITE),
+        (
+            format!("🎚️ Difficulty: {:?} - {}", *difficulty, presets.get(*difficulty).description),
+            20.0,
+            Color::rgb(1.0, 0.8, 0.4),
+        ),
+        ("1: Recruit | 2: Historical | 3: Veteran".to_string(), 20.0, Color::rgb(0.8, 0.8, 0.8)),
+        ("ENTER: Begin".to_string(), 22.0, Color::rgb(0.8, 0.8, 0.8)),
+    ]);
+}
+
+fn spawn_pause_screen(mut commands: Commands) {
+    spawn_overlay(&mut commands, PauseScreen, Color::rgba(0.0, 0.0, 0.0, 0.6), vec![
+        ("⏸️ PAUSED".to_string(), 48.0, Color::WHITE),
+        ("ESC / P: Resume | Q: Quit".to_string(), 22.0, Color::rgb(0.8, 0.8, 0.8)),
+    ]);
+}
+
+// How the match went, as the results screen reports it
+#[derive(SystemParam)]
+struct MatchReport<'w, 's> {
+    game_state: Res<'w, GameState>,
+    stats: Res<'w, MatchStats>,
+    metadata: Res<'w, OvidioMetadata>,
+    pressure: Res<'w, PoliticalPressure>,
+    zone_query: Query<'w, 's, (&'static Zone, &'static ZoneControl)>,
+}
+
+fn spawn_results_screen(
+    mut commands: Commands,
+    report: MatchReport,
+    side: Res<PlayerSide>,
+    difficulty: Res<Difficulty>,
+) {
+    let MatchReport { game_state, stats, metadata, pressure, zone_query } = report;
+    let Some(outcome) = game_state.outcome else {
+        return;
+    };
+    let verdict = match side.faction() {
+        Some(faction) if faction == outcome.winner() => "🏆 VICTORY",
+        Some(_) => "💀 DEFEAT",
+        None => "🤖 AI vs AI",
+    };
+
+    let mut losses: Vec<String> = stats.units_lost.iter()
+        .map(|(unit_type, count)| format!("{:?}: {}", unit_type, count))
+        .collect();
+    losses.sort();
+    let losses = if losses.is_empty() { "none".to_string() } else { losses.join(", ") };
+    let city_control = CityControl::evaluate(zone_query.iter());
+
+    spawn_overlay(&mut commands, ResultsScreen, Color::rgba(0.05, 0.05, 0.05, 0.92), vec![
+        (verdict.to_string(), 44.0, Color::WHITE),
+        (outcome.headline().to_string(), 34.0, Color::rgb(1.0, 0.9, 0.6)),
+        (outcome.epilogue().to_string(), 22.0, Color::WHITE),
+        (String::new(), 20.0, Color::WHITE),
+        (
+            format!("⏱️ Time: {:.0}s | Waves: {} | Difficulty: {:?}", game_state.mission_timer, game_state.current_wave, *difficulty),
+            20.0,
+            Color::WHITE,
+        ),
+        (format!("Cartel: {} | Military: {}", game_state.cartel_score, game_state.military_score), 20.0, Color::WHITE),
+        (format!("Losses - {}", losses), 18.0, Color::rgb(0.8, 0.8, 0.8)),
+        (
+            format!(
+                "🕊️ Civilian casualties: {} (cartel fire: {}, military fire: {})",
+                game_state.civilian_casualties.total(),
+                game_state.civilian_casualties.by_cartel,
+                game_state.civilian_casualties.by_military,
+            ),
+            20.0,
+            Color::rgb(1.0, 0.6, 0.6),
+        ),
+        (
+            format!("🏛️ Political pressure: {:.0}% of the release threshold", pressure.progress(&metadata) * 100.0),
+            20.0,
+            Color::rgb(1.0, 0.8, 0.4),
+        ),
+        (
+            format!(
+                "🗺️ City control: Cartel {:.0}% | Military {:.0}% - {}",
+                city_control.cartel * 100.0,
+                city_control.military * 100.0,
+                city_control.verdict(),
+            ),
+            20.0,
+            Color::WHITE,
+        ),
+        (String::new(), 20.0, Color::WHITE),
+        ("📚 The real Battle of Culiacán ended with the government releasing Ovidio Guzmán López".to_string(), 18.0, Color::rgb(0.7, 0.7, 0.7)),
+        ("ESC: Quit".to_string(), 22.0, Color::rgb(0.8, 0.8, 0.8)),
+    ]);
+}
+
+// ==================== TRANSITIONS ====================
+
+fn main_menu_input(
+    input: Res<Input<KeyCode>>,
+    mut side: ResMut<PlayerSide>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // ENTER keeps the side given on the command line
+    let choice = if input.just_pressed(KeyCode::C) {
+        Some(PlayerSide::Cartel)
+    } else if input.just_pressed(KeyCode::M) {
+        Some(PlayerSide::Military)
+    } else if input.just_pressed(KeyCode::V) {
+        Some(PlayerSide::Spectator)
+    } else if input.just_pressed(KeyCode::Return) {
+        Some(*side)
+    } else {
+        None
+    };
+
+    if let Some(choice) = choice {
+        *side = choice;
+        info!("🎖️ Playing side: {}", choice.name());
+        next_state.set(AppState::Briefing);
+    } else if input.just_pressed(KeyCode::Escape) {
+        exit.send(AppExit);
+    }
+}
+
+fn briefing_input(
+    input: Res<Input<KeyCode>>,
+    side: Res<PlayerSide>,
+    mut difficulty: ResMut<Difficulty>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let keys = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3];
+    let levels = [Difficulty::Recruit, Difficulty::Historical, Difficulty::Veteran];
+    if let Some((_, level)) = keys.iter().zip(levels).find(|(key, _)| input.just_pressed(**key)) {
+        *difficulty = level;
+    }
+
+    if input.just_pressed(KeyCode::Return) || input.just_pressed(KeyCode::Space) {
+        match *side {
+            PlayerSide::Cartel => info!("🚁 Operation under way - defend the safehouse!"),
+            PlayerSide::Military => info!("🚁 Operation under way - secure the target!"),
+            PlayerSide::Spectator => info!("🚁 Operation under way - both sides on AI command"),
+        }
+        next_state.set(AppState::InGame);
+    }
+}
+
+fn pause_input(
+    input: Res<Input<KeyCode>>,
+    state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if input.just_pressed(KeyCode::Escape) || input.just_pressed(KeyCode::P) {
+        match state.get() {
+            AppState::InGame => {
+                info!("⏸️ Simulation paused");
+                next_state.set(AppState::Paused);
+            }
+            _ => {
+                info!("▶️ Simulation resumed");
+                next_state.set(AppState::InGame);
+            }
+        }
+    } else if *state.get() == AppState::Paused && input.just_pressed(KeyCode::Q) {
+        exit.send(AppExit);
+    }
+}
+
+// Moves to the results screen as soon as the simulation decides the match
+fn match_end_system(game_state: Res<GameState>, mut next_state: ResMut<NextState<AppState>>) {
+    if game_state.outcome.is_some() {
+        next_state.set(AppState::Results);
+    }
+}
+
+fn results_input(input: Res<Input<KeyCode>>, mut exit: EventWriter<AppExit>) {
+    if input.just_pressed(KeyCode::Escape) {
+        info!("🏛️ SIMULATION ENDED");
+        exit.send(AppExit);
+    }
+}
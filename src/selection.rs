@@ -0,0 +1,199 @@
+// ==================== SELECTION & ORDERS ====================
+// Click or drag-box to select your own units, right-click to move or attack.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::placement::RoadblockPlacement;
+use crate::sim::{PlayerCommand, PlayerCommands, PlayerSide};
+use crate::{iso_to_world, Faction, IsometricCamera, Unit, UnitType};
+
+// Drag distance (in screen-space world units) below which a release counts as a click
+const CLICK_THRESHOLD: f32 = 6.0;
+const PICK_MARGIN: f32 = 6.0;
+
+#[derive(Component)]
+pub struct Selected;
+
+// Set on units whose current target came from a player attack order,
+// so they chase it instead of dropping it when it leaves weapon range
+#[derive(Component)]
+pub struct AttackOrder;
+
+#[derive(Resource, Default)]
+pub struct DragSelection {
+    start: Option<Vec2>,
+    current: Vec2,
+}
+
+// What the player presses and points at, and which side they play
+#[derive(SystemParam)]
+pub struct PlayerInput<'w, 's> {
+    pub mouse: Res<'w, Input<MouseButton>>,
+    pub keys: Res<'w, Input<KeyCode>>,
+    pub side: Res<'w, PlayerSide>,
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<IsometricCamera>>,
+}
+
+impl PlayerInput<'_, '_> {
+    // The cursor in projected screen space, if it is over the window
+    pub fn cursor(&self) -> Option<Vec2> {
+        let window = self.windows.get_single().ok()?;
+        let cursor = window.cursor_position()?;
+        let (camera, camera_transform) = self.cameras.get_single().ok()?;
+        camera.viewport_to_world_2d(camera_transform, cursor)
+    }
+}
+
+fn pick_radius(sprite: &Sprite) -> f32 {
+    sprite.custom_size.map(|size| size.max_element() * 0.5).unwrap_or(10.0) + PICK_MARGIN
+}
+
+// Finds the unit of the given faction closest to the cursor, if any is under it
+fn unit_under_cursor<'a>(
+    cursor: Vec2,
+    faction: Faction,
+    units: impl Iterator<Item = (Entity, &'a Unit, &'a Transform, &'a Sprite)>,
+) -> Option<Entity> {
+    units
+        .filter(|(_, unit, _, _)| unit.faction == faction)
+        .map(|(entity, _, transform, sprite)| {
+            (entity, transform.translation.truncate().distance(cursor), pick_radius(sprite))
+        })
+        .filter(|(_, distance, radius)| distance <= radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _, _)| entity)
+}
+
+fn is_commandable(unit: &Unit, faction: &Faction) -> bool {
+    unit.faction == *faction && unit.unit_type != UnitType::Roadblock
+}
+
+// Whoever the player's side is fighting
+fn opponent(faction: &Faction) -> Faction {
+    match faction {
+        Faction::Military => Faction::Cartel,
+        _ => Faction::Military,
+    }
+}
+
+pub fn selection_system(
+    mut commands: Commands,
+    input: PlayerInput,
+    mut drag: ResMut<DragSelection>,
+    unit_query: Query<(Entity, &Unit, &Transform, &Sprite)>,
+    selected_query: Query<Entity, With<Selected>>,
+    placement: Res<RoadblockPlacement>,
+) {
+    // Left clicks belong to the roadblock ghost while placing
+    if placement.active {
+        drag.start = None;
+        return;
+    }
+    // Spectators watch, they do not command
+    let Some(faction) = input.side.faction() else {
+        return;
+    };
+    let Some(cursor) = input.cursor() else {
+        return;
+    };
+
+    if input.mouse.just_pressed(MouseButton::Left) {
+        drag.start = Some(cursor);
+    }
+    drag.current = cursor;
+
+    if !input.mouse.just_released(MouseButton::Left) {
+        return;
+    }
+    let Some(start) = drag.start.take() else {
+        return;
+    };
+
+    let additive = input.keys.pressed(KeyCode::ShiftLeft) || input.keys.pressed(KeyCode::ShiftRight);
+    if !additive {
+        for entity in selected_query.iter() {
+            commands.entity(entity).remove::<Selected>();
+        }
+    }
+
+    if start.distance(cursor) < CLICK_THRESHOLD {
+        // Single click picks the unit under the cursor
+        let commandable = unit_query.iter().filter(|(_, unit, _, _)| is_commandable(unit, &faction));
+        if let Some(entity) = unit_under_cursor(cursor, faction.clone(), commandable) {
+            commands.entity(entity).insert(Selected);
+        }
+    } else {
+        // Drag box selects every commandable unit inside it
+        let min = start.min(cursor);
+        let max = start.max(cursor);
+        for (entity, unit, transform, _) in unit_query.iter() {
+            let position = transform.translation.truncate();
+            if is_commandable(unit, &faction) && position.cmpge(min).all() && position.cmple(max).all() {
+                commands.entity(entity).insert(Selected);
+            }
+        }
+    }
+}
+
+pub fn order_system(
+    input: PlayerInput,
+    target_query: Query<(Entity, &Unit, &Transform, &Sprite), Without<Selected>>,
+    selected_query: Query<(Entity, &Unit), With<Selected>>,
+    mut player_commands: ResMut<PlayerCommands>,
+    placement: Res<RoadblockPlacement>,
+) {
+    // Right clicks cancel the roadblock ghost while placing
+    if placement.active || !input.mouse.just_pressed(MouseButton::Right) || selected_query.is_empty() {
+        return;
+    }
+    let Some(faction) = input.side.faction() else {
+        return;
+    };
+    let Some(cursor) = input.cursor() else {
+        return;
+    };
+
+    // Ovidio is to be taken alive, so soldiers walk up to him rather than shoot
+    let targets = target_query.iter().filter(|(_, unit, _, _)| unit.unit_type != UnitType::Ovidio);
+    if let Some(enemy) = unit_under_cursor(cursor, opponent(&faction), targets) {
+        // Ovidio does not fight
+        let units = selected_query.iter()
+            .filter(|(_, unit)| unit.damage > 0.0)
+            .map(|(entity, _)| entity)
+            .collect();
+        player_commands.push(PlayerCommand::Attack { units, target: enemy });
+        match faction {
+            Faction::Military => info!("🎯 Attack order issued! 📻 'Engage that target!'"),
+            _ => info!("🎯 Attack order issued! 📻 '¡Denle a ese!'"),
+        }
+    } else {
+        // The cursor is in projected screen space; orders are given on the map
+        let units = selected_query.iter().map(|(entity, _)| entity).collect();
+        player_commands.push(PlayerCommand::Move { units, target: iso_to_world(cursor.extend(0.0)) });
+        match faction {
+            Faction::Military => info!("📍 Move order issued! 📻 'Moving out!'"),
+            _ => info!("📍 Move order issued! 📻 '¡Vámonos, muchachos!'"),
+        }
+    }
+}
+
+pub fn selection_highlight_system(
+    mut gizmos: Gizmos,
+    drag: Res<DragSelection>,
+    selected_query: Query<(&Transform, &Sprite), With<Selected>>,
+) {
+    for (transform, sprite) in selected_query.iter() {
+        gizmos.circle_2d(transform.translation.truncate(), pick_radius(sprite), Color::rgb(1.0, 0.9, 0.2));
+    }
+
+    if let Some(start) = drag.start {
+        if start.distance(drag.current) >= CLICK_THRESHOLD {
+            let center = (start + drag.current) * 0.5;
+            let size = (drag.current - start).abs();
+            gizmos.rect_2d(center, 0.0, size, Color::rgb(0.3, 1.0, 0.3));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// ==================== SIMULATION CORE ====================
+// Gameplay runs in FixedUpdate at SIM_HZ with a single seeded RNG, and player
+// input is queued as commands that are applied at the start of a fixed tick.
+// The same seed and command stream therefore always replays the same match.
+
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+pub const SIM_HZ: f64 = 60.0;
+
+// The generator behind rand's StdRng, used directly because it can report
+// and restore its position in the stream
+#[derive(Resource)]
+pub struct SimRng {
+    pub seed: u64,
+    pub rng: ChaCha12Rng,
+}
+
+// Where the simulation RNG stands, for saves
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RngState {
+    key: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+impl SimRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn state(&self) -> RngState {
+        RngState {
+            key: self.rng.get_seed(),
+            stream: self.rng.get_stream(),
+            word_pos: self.rng.get_word_pos(),
+        }
+    }
+
+    // Picks the stream up where `state` left it, keeping the match seed for reporting
+    pub fn restore(&mut self, state: &RngState) {
+        self.rng = ChaCha12Rng::from_seed(state.key);
+        self.rng.set_stream(state.stream);
+        self.rng.set_word_pos(state.word_pos);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PlayerCommand {
+    // Build a roadblock on the map, facing `heading` (radians)
+    DeployRoadblock { position: Vec3, heading: f32 },
+    CallReinforcements,
+    Move { units: Vec<Entity>, target: Vec3 },
+    Attack { units: Vec<Entity>, target: Entity },
+    // Move Ovidio out along the indexed escape route
+    Escape { route: usize },
+}
+
+// Which side the human commands; every other faction is run by the AI.
+// Spectator leaves both to the AI, for AI-vs-AI matches.
+#[derive(Resource, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum PlayerSide {
+    #[default]
+    Cartel,
+    Military,
+    Spectator,
+}
+
+impl PlayerSide {
+    pub fn faction(&self) -> Option<crate::Faction> {
+        match self {
+            PlayerSide::Cartel => Some(crate::Faction::Cartel),
+            PlayerSide::Military => Some(crate::Faction::Military),
+            PlayerSide::Spectator => None,
+        }
+    }
+
+    pub fn is_ai(&self, faction: &crate::Faction) -> bool {
+        self.faction().as_ref() != Some(faction)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerSide::Cartel => "cartel",
+            PlayerSide::Military => "military",
+            PlayerSide::Spectator => "none",
+        }
+    }
+}
+
+impl std::str::FromStr for PlayerSide {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cartel" => Ok(PlayerSide::Cartel),
+            "military" => Ok(PlayerSide::Military),
+            "none" | "ai" => Ok(PlayerSide::Spectator),
+            _ => Err(()),
+        }
+    }
+}
+
+// A single shot from `from` at a target standing at `to`, for anything that
+// reacts to gunfire beyond the intended target
+#[derive(Event, Clone, Debug)]
+pub struct ShotFired {
+    pub faction: crate::Faction,
+    pub from: Vec3,
+    pub to: Vec3,
+    pub damage: f32,
+}
+
+// Commands issued from input systems in Update, drained by the next fixed tick
+#[derive(Resource, Default)]
+pub struct PlayerCommands {
+    pub queue: Vec<PlayerCommand>,
+}
+
+impl PlayerCommands {
+    pub fn push(&mut self, command: PlayerCommand) {
+        self.queue.push(command);
+    }
+}
@@ -0,0 +1,358 @@
+// ==================== MILITARY SQUADS ====================
+// Each wave is split into fireteams with an operational objective: assault
+// Ovidio, escort him once he is taken, or secure a strategic point. Teams
+// advance in a wedge behind their leader. On contact half the team pins the
+// enemy down while the other half swings around a flank, roadblocks across
+// the line of advance are shot out first, and wounded soldiers fall back to
+// the team's rally point until they are fit to rejoin.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::capture::OvidioStatus;
+use crate::difficulty::{acquire_target, DifficultyPreset};
+use crate::nav::NavGrid;
+use crate::strategic::StrategicPoint;
+use crate::{Faction, GameState, Movement, Objective, ObjectiveType, Unit, UnitType, WorldPosition};
+
+pub const FIRETEAM_SIZE: u32 = 4;
+
+// Spacing between ranks of the wedge
+const FORMATION_SPACING: f32 = 25.0;
+// Enemies this many times a soldier's range from the team bring it into contact
+const CONTACT_RANGE_FACTOR: f32 = 1.2;
+// Flankers take position this share of their range off the enemy's side
+const FLANK_DISTANCE_FACTOR: f32 = 0.7;
+const FLANK_ARRIVAL_RADIUS: f32 = 25.0;
+// Roadblocks this far ahead and this close to the line of advance get cleared
+const ROADBLOCK_LOOKAHEAD: f32 = 200.0;
+const ROADBLOCK_CORRIDOR: f32 = 60.0;
+// Escorts hang back this far from Ovidio until he is in custody
+const ESCORT_STANDOFF: f32 = 150.0;
+// Soldiers fall back below RETREAT_HEALTH and rejoin at REJOIN_HEALTH
+const RETREAT_HEALTH: f32 = 0.35;
+const REJOIN_HEALTH: f32 = 0.8;
+const RALLY_RADIUS: f32 = 40.0;
+const RALLY_HEAL_PER_SECOND: f32 = 4.0;
+
+// Pinned-down shooters hit less, and pinned targets are easier to hit from elsewhere
+pub const SUPPRESSED_DAMAGE: f32 = 0.5;
+pub const FLANK_DAMAGE: f32 = 1.5;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum SquadObjective {
+    // Take Ovidio, falling back on the safehouse
+    Assault,
+    // Stay with Ovidio once he is in custody
+    Escort,
+    // Take and hold the named strategic point
+    Secure(String),
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Debug, Serialize, Deserialize)]
+pub enum SquadRole {
+    #[default]
+    Advance,
+    Suppress,
+    Flank,
+    Retreat,
+}
+
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+pub struct SquadMember {
+    pub squad: u32,
+    // Order within the team; the lowest fit slot leads
+    pub slot: u32,
+    #[serde(default)]
+    pub role: SquadRole,
+}
+
+#[derive(Clone, Debug)]
+pub struct Squad {
+    pub objective: SquadObjective,
+    // Where the team entered the city and where its wounded fall back to
+    pub rally: Vec3,
+    // Whether the team is busy shooting out a roadblock, for the radio chatter
+    pub clearing_roadblock: bool,
+}
+
+#[derive(Resource, Clone, Default, Debug)]
+pub struct Squads {
+    pub next_id: u32,
+    pub squads: BTreeMap<u32, Squad>,
+}
+
+impl Squads {
+    pub fn raise(&mut self, objective: SquadObjective, rally: Vec3) -> u32 {
+        self.next_id += 1;
+        self.squads.insert(self.next_id, Squad {
+            objective,
+            rally,
+            clearing_roadblock: false,
+        });
+        self.next_id
+    }
+}
+
+impl SquadObjective {
+    pub fn describe(&self) -> String {
+        match self {
+            SquadObjective::Assault => "take Ovidio at the safehouse".to_string(),
+            SquadObjective::Escort => "escort the target out once he is detained".to_string(),
+            SquadObjective::Secure(name) => format!("secure the {}", name),
+        }
+    }
+}
+
+// Objective for the `team`-th fireteam of a wave: the first assaults, the
+// second escorts on even waves, and the rest take strategic points the
+// military does not hold yet, most important first
+pub fn wave_objective<'a>(
+    wave: u32,
+    team: usize,
+    points: impl Iterator<Item = &'a StrategicPoint>,
+) -> SquadObjective {
+    if team == 0 {
+        return SquadObjective::Assault;
+    }
+    if team == 1 && wave.is_multiple_of(2) {
+        return SquadObjective::Escort;
+    }
+
+    let mut open: Vec<&StrategicPoint> = points.filter(|point| !point.owned_by(&Faction::Military)).collect();
+    open.sort_by(|a, b| b.importance.total_cmp(&a.importance).then_with(|| a.name.cmp(&b.name)));
+    match open.get((team - 1) % open.len().max(1)) {
+        Some(point) => SquadObjective::Secure(point.name.clone()),
+        None => SquadObjective::Assault,
+    }
+}
+
+// Wedge position of the `rank`-th soldier behind a leader facing `heading`
+fn formation_offset(rank: usize, heading: Vec2) -> Vec3 {
+    if rank == 0 {
+        return Vec3::ZERO;
+    }
+    let row = rank.div_ceil(2) as f32;
+    let side = if rank % 2 == 1 { 1.0 } else { -1.0 };
+    let local = Vec2::new(-FORMATION_SPACING * row, side * FORMATION_SPACING * 0.8 * row);
+    heading.rotate(local).extend(0.0)
+}
+
+// Enemies held down by a suppressing soldier in range, and who is holding them
+pub fn pinned_targets(units: &[(Entity, &Unit, Vec3, Option<&SquadMember>)]) -> HashMap<Entity, Entity> {
+    let positions: HashMap<Entity, Vec3> = units.iter().map(|(entity, _, position, _)| (*entity, *position)).collect();
+    units.iter()
+        .filter(|(_, _, _, member)| member.is_some_and(|member| member.role == SquadRole::Suppress))
+        .filter_map(|(entity, unit, position, _)| {
+            let target = unit.target?;
+            let target_position = positions.get(&target)?;
+            (position.distance(*target_position) <= unit.range).then_some((target, *entity))
+        })
+        .collect()
+}
+
+// The match state fireteams plan against but never change
+#[derive(SystemParam)]
+pub struct SquadContext<'w> {
+    time: Res<'w, Time>,
+    game_state: Res<'w, GameState>,
+    preset: Res<'w, DifficultyPreset>,
+    grid: Res<'w, NavGrid>,
+}
+
+pub fn squad_ai_system(
+    context: SquadContext,
+    mut squads: ResMut<Squads>,
+    mut member_query: Query<(Entity, &mut Unit, &WorldPosition, &mut Movement, &mut SquadMember)>,
+    other_query: Query<(Entity, &Unit, &WorldPosition), Without<SquadMember>>,
+    objective_query: Query<(&Objective, Option<&StrategicPoint>)>,
+) {
+    let SquadContext { time, game_state, preset, grid } = context;
+    let dt = time.delta_seconds();
+    let ovidio_targetable = matches!(game_state.ovidio_status, OvidioStatus::Escaping { .. });
+
+    // Cartel gunmen and roadblocks, kept apart; Ovidio is only fair game when he runs
+    let mut enemies = Vec::new();
+    let mut roadblocks = Vec::new();
+    let mut ovidio = None;
+    for (entity, unit, position) in other_query.iter() {
+        if unit.faction != Faction::Cartel {
+            continue;
+        }
+        match unit.unit_type {
+            UnitType::Roadblock => roadblocks.push((entity, position.0)),
+            UnitType::Ovidio => {
+                ovidio = Some(position.0);
+                if ovidio_targetable {
+                    enemies.push((entity, position.0));
+                }
+            }
+            _ => enemies.push((entity, position.0)),
+        }
+    }
+    let safehouse = objective_query.iter()
+        .find(|(objective, _)| objective.objective_type == ObjectiveType::Safehouse)
+        .map(|(objective, _)| objective.position);
+
+    // Wounded soldiers fall back first, so they drop out of the team below
+    for (_, mut unit, position, mut movement, mut member) in member_query.iter_mut() {
+        let Some(squad) = squads.squads.get(&member.squad) else {
+            continue;
+        };
+        let health = unit.health / unit.max_health;
+        if member.role != SquadRole::Retreat && health < RETREAT_HEALTH {
+            info!("📻 'Man down in fireteam {}! Falling back!'", member.squad);
+            member.role = SquadRole::Retreat;
+        }
+        if member.role != SquadRole::Retreat {
+            continue;
+        }
+
+        unit.target = None;
+        if position.0.distance(squad.rally) <= RALLY_RADIUS {
+            movement.target_position = None;
+            unit.health = (unit.health + RALLY_HEAL_PER_SECOND * dt).min(unit.max_health);
+            if unit.health / unit.max_health >= REJOIN_HEALTH {
+                info!("📻 'Fireteam {}, patched up and moving back in'", member.squad);
+                member.role = SquadRole::Advance;
+            }
+        } else {
+            movement.target_position = Some(squad.rally);
+        }
+    }
+
+    // Soldiers still in the fight, as (entity, slot, position, range) per team
+    let mut teams: BTreeMap<u32, Vec<(Entity, u32, Vec3, f32)>> = BTreeMap::new();
+    for (entity, unit, position, _, member) in member_query.iter() {
+        let team = teams.entry(member.squad).or_default();
+        if member.role != SquadRole::Retreat {
+            team.push((entity, member.slot, position.0, unit.range));
+        }
+    }
+
+    // Teams with nobody left are struck off
+    let lost: Vec<u32> = squads.squads.keys().copied().filter(|id| !teams.contains_key(id)).collect();
+    for id in lost {
+        squads.squads.remove(&id);
+        info!("📻 'Command, fireteam {} is gone!'", id);
+    }
+
+    for (id, mut fit) in teams {
+        let Some(squad) = squads.squads.get_mut(&id) else {
+            continue;
+        };
+        if fit.is_empty() {
+            continue;
+        }
+        fit.sort_by_key(|(_, slot, _, _)| *slot);
+        let leader = fit[0].2;
+        let centroid = fit.iter().map(|(_, _, position, _)| *position).sum::<Vec3>() / fit.len() as f32;
+        let reach = fit.iter().map(|(_, _, _, range)| *range).fold(0.0, f32::max) * CONTACT_RANGE_FACTOR;
+
+        let goal = match &squad.objective {
+            SquadObjective::Assault => ovidio.or(safehouse),
+            SquadObjective::Escort => {
+                let target = ovidio.or(safehouse);
+                if game_state.ovidio_status.in_custody() {
+                    target
+                } else {
+                    // Hang back on the rally side until there is someone to escort
+                    target.map(|target| target + (squad.rally - target).normalize_or_zero() * ESCORT_STANDOFF)
+                }
+            }
+            SquadObjective::Secure(name) => objective_query.iter()
+                .find(|(_, point)| point.is_some_and(|point| &point.name == name))
+                .map(|(objective, _)| objective.position)
+                .or(safehouse),
+        };
+
+        // Contact: the enemy nearest the team's centre within reach of any member
+        let contact = enemies.iter()
+            .filter(|(_, enemy)| fit.iter().any(|(_, _, position, _)| position.distance(*enemy) <= reach))
+            .min_by(|a, b| centroid.distance(a.1).total_cmp(&centroid.distance(b.1)))
+            .copied();
+
+        // A roadblock straight across the line of advance
+        let blocking = goal.and_then(|goal| {
+            let to_goal = (goal - leader).truncate();
+            let direction = to_goal.normalize_or_zero();
+            roadblocks.iter()
+                .filter_map(|(entity, roadblock)| {
+                    let offset = (*roadblock - leader).truncate();
+                    let along = offset.dot(direction);
+                    let across = offset.perp_dot(direction).abs();
+                    (along > 0.0 && along <= ROADBLOCK_LOOKAHEAD.min(to_goal.length() + ROADBLOCK_CORRIDOR)
+                        && across <= ROADBLOCK_CORRIDOR)
+                        .then_some((*entity, along))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(entity, _)| entity)
+        });
+        let clearing = contact.is_none() && blocking.is_some();
+        if clearing && !squad.clearing_roadblock {
+            info!("📻 'Fireteam {}: roadblock ahead, taking it down!'", id);
+        }
+        squad.clearing_roadblock = clearing;
+
+        let heading = goal
+            .map(|goal| (goal - leader).truncate().normalize_or_zero())
+            .filter(|heading| *heading != Vec2::ZERO)
+            .unwrap_or(Vec2::NEG_X);
+
+        for (rank, (entity, _, position, _)) in fit.iter().enumerate() {
+            let Ok((_, mut unit, _, mut movement, mut member)) = member_query.get_mut(*entity) else {
+                continue;
+            };
+
+            if let Some((enemy, enemy_position)) = contact {
+                // Even ranks pin the enemy down, odd ranks work round alternate sides
+                let side = if rank % 4 == 1 { 1.0 } else { -1.0 };
+                let toward = (enemy_position - centroid).truncate().normalize_or_zero();
+                let flank_point = enemy_position + (toward.perp() * side * unit.range * FLANK_DISTANCE_FACTOR).extend(0.0);
+                let can_flank = rank % 2 == 1
+                    && grid.contains(flank_point)
+                    && !grid.is_blocked(grid.world_to_cell(flank_point));
+
+                if can_flank {
+                    member.role = SquadRole::Flank;
+                    if unit.target.is_none() {
+                        if position.distance(flank_point) <= FLANK_ARRIVAL_RADIUS {
+                            acquire_target(&mut unit, enemy, preset.ai_reaction);
+                        } else {
+                            movement.target_position = Some(flank_point);
+                        }
+                    }
+                } else {
+                    member.role = SquadRole::Suppress;
+                    if unit.target.is_none() {
+                        acquire_target(&mut unit, enemy, preset.ai_reaction);
+                        movement.target_position = None;
+                    }
+                }
+                continue;
+            }
+
+            member.role = SquadRole::Advance;
+            if let (true, Some(roadblock)) = (clearing, blocking) {
+                if unit.target.is_none() {
+                    acquire_target(&mut unit, roadblock, preset.ai_reaction);
+                }
+                continue;
+            }
+
+            // Anyone still in a firefight finishes it before moving on
+            if unit.target.is_some() {
+                continue;
+            }
+            if let Some(goal) = goal {
+                movement.target_position = Some(if rank == 0 {
+                    goal
+                } else {
+                    leader + formation_offset(rank, heading)
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// ==================== STRATEGIC POINTS ====================
+// Checkpoints from the map's strategic_points. A faction standing on a point
+// unopposed for requires_holding_time takes it over; owned points pay score
+// income by importance and shield their defenders by defensive_bonus.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::map::StrategicPointData;
+use crate::{Faction, GameState, Objective, Unit, UnitType, WorldPosition};
+
+// Score per second for each point of importance held
+const SCORE_PER_IMPORTANCE: f32 = 0.2;
+
+#[derive(Component, Clone, Debug)]
+pub struct StrategicPoint {
+    pub name: String,
+    pub importance: f32,
+    pub requires_holding_time: f32,
+    pub defensive_bonus: f32,
+    pub control: PointControl,
+}
+
+// Everything about a point that changes during a match
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct PointControl {
+    pub owner: Option<Faction>,
+    // Faction currently taking the point and how long it has held it
+    pub capturing: Option<Faction>,
+    pub progress: f32,
+    // Fractional score not paid out yet
+    pub income_carry: f32,
+}
+
+impl StrategicPoint {
+    pub fn from_data(data: &StrategicPointData) -> Self {
+        Self {
+            name: data.name.clone(),
+            importance: data.importance,
+            requires_holding_time: data.requires_holding_time,
+            defensive_bonus: data.defensive_bonus,
+            control: PointControl::default(),
+        }
+    }
+
+    pub fn owned_by(&self, faction: &Faction) -> bool {
+        self.control.owner.as_ref() == Some(faction)
+    }
+}
+
+// Damage multiplier for a unit of `faction` standing at `position`
+pub fn defensive_multiplier<'a>(
+    points: impl Iterator<Item = (&'a Objective, &'a StrategicPoint)>,
+    faction: &Faction,
+    position: Vec3,
+) -> f32 {
+    points
+        .filter(|(objective, point)| point.owned_by(faction) && position.distance(objective.position) <= objective.radius)
+        .map(|(_, point)| 1.0 - point.defensive_bonus.clamp(0.0, 1.0))
+        .fold(1.0, f32::min)
+}
+
+pub fn strategic_point_system(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    unit_query: Query<(&Unit, &WorldPosition)>,
+    mut point_query: Query<(&Objective, &mut StrategicPoint)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (objective, mut point) in point_query.iter_mut() {
+        let mut cartel = 0;
+        let mut military = 0;
+        for (unit, position) in unit_query.iter() {
+            // Roadblocks and Ovidio hold ground, but nobody takes a point with them
+            if matches!(unit.unit_type, UnitType::Roadblock | UnitType::Ovidio)
+                || position.0.distance(objective.position) > objective.radius
+            {
+                continue;
+            }
+            match unit.faction {
+                Faction::Cartel => cartel += 1,
+                Faction::Military => military += 1,
+                Faction::Civilian => {}
+            }
+        }
+
+        let present = match (cartel > 0, military > 0) {
+            (true, false) => Some(Faction::Cartel),
+            (false, true) => Some(Faction::Military),
+            _ => None,
+        };
+
+        let control = &mut point.control;
+        match present {
+            Some(faction) if control.owner.as_ref() != Some(&faction) => {
+                if control.capturing.as_ref() != Some(&faction) {
+                    control.capturing = Some(faction.clone());
+                    control.progress = 0.0;
+                }
+                control.progress += dt;
+            }
+            // Held by its owner: any capture attempt is beaten back
+            Some(_) => {
+                control.capturing = None;
+                control.progress = 0.0;
+            }
+            // Contested or empty: a capture in progress stalls and slips back
+            None => {
+                control.progress = (control.progress - dt).max(0.0);
+                if control.progress == 0.0 {
+                    control.capturing = None;
+                }
+            }
+        }
+
+        if control.progress >= point.requires_holding_time {
+            let faction = point.control.capturing.take();
+            point.control.progress = 0.0;
+            point.control.income_carry = 0.0;
+            match faction {
+                Some(Faction::Cartel) => info!("🚩 Cartel takes the {}! 📻 '¡La plaza es nuestra!'", point.name),
+                Some(Faction::Military) => info!("🎖️ Military secures the {}", point.name),
+                _ => {}
+            }
+            point.control.owner = faction;
+        }
+
+        // Income from the point's owner
+        let income = point.importance * SCORE_PER_IMPORTANCE * dt;
+        let control = &mut point.control;
+        if control.owner.is_some() {
+            control.income_carry += income;
+            let whole = control.income_carry.floor();
+            control.income_carry -= whole;
+            match control.owner {
+                Some(Faction::Cartel) => game_state.cartel_score += whole as u32,
+                Some(Faction::Military) => game_state.military_score += whole as u32,
+                _ => {}
+            }
+        }
+    }
+}
+
+// ==================== FLAGS ====================
+
+#[derive(Component)]
+pub struct StrategicPointLabel;
+
+// Tints each point by owner and shows capture progress in its label
+pub fn strategic_point_flag_system(
+    mut point_query: Query<(&StrategicPoint, &mut Sprite, &Children)>,
+    mut label_query: Query<&mut Text, With<StrategicPointLabel>>,
+) {
+    for (point, mut sprite, children) in point_query.iter_mut() {
+        let control = &point.control;
+        sprite.color = match control.owner {
+            Some(Faction::Cartel) => Color::rgba(0.9, 0.2, 0.2, 0.8),
+            Some(Faction::Military) => Color::rgba(0.2, 0.6, 0.2, 0.8),
+            _ => Color::rgba(0.9, 0.9, 0.3, 0.6),
+        };
+
+        let flag = match control.owner {
+            Some(Faction::Cartel) => "🔴",
+            Some(Faction::Military) => "🟢",
+            _ => "🚩",
+        };
+        let progress = match control.capturing {
+            Some(_) => format!(" ({:.0}%)", control.progress / point.requires_holding_time * 100.0),
+            None => String::new(),
+        };
+
+        for &child in children.iter() {
+            if let Ok(mut text) = label_query.get_mut(child) {
+                text.sections[0].value = format!("{} {}{}", flag, point.name, progress);
+            }
+        }
+    }
+}
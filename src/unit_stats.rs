@@ -0,0 +1,184 @@
+// ==================== UNIT STATS ====================
+// Unit archetypes live in assets/data/unit_stats.json so balancing passes do
+// not need a recompile. Debug builds watch the file and hot reload it.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use crate::data;
+use crate::difficulty::DifficultyPreset;
+use crate::pressure::is_military_unit;
+use crate::{Movement, Unit, UnitType};
+
+pub const UNIT_STATS_PATH: &str = "assets/data/unit_stats.json";
+
+const BUILTIN_UNIT_STATS: &str = include_str!("../assets/data/unit_stats.json");
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct UnitStats {
+    pub label: String,
+    pub color: [f32; 3],
+    pub size: [f32; 2],
+    pub health: f32,
+    pub damage: f32,
+    pub range: f32,
+    pub speed: f32,
+    pub attack_cooldown: f32,
+    // Infantry a vehicle carries into the fight
+    #[serde(default)]
+    pub passengers: Vec<UnitType>,
+}
+
+impl UnitStats {
+    pub fn color(&self) -> Color {
+        Color::rgb(self.color[0], self.color[1], self.color[2])
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.size[0], self.size[1])
+    }
+
+    pub fn cooldown(&self) -> Duration {
+        Duration::from_secs_f32(self.attack_cooldown)
+    }
+
+    fn problems(&self, unit_type: &str) -> Vec<String> {
+        let values = [
+            ("health", self.health),
+            ("range", self.range),
+            ("speed", self.speed),
+            ("attack_cooldown", self.attack_cooldown),
+        ];
+        values.iter()
+            .filter(|(_, value)| !value.is_finite() || *value < 0.0)
+            .map(|(field, value)| format!("{} has {} {}, expected a number of at least 0", unit_type, field, value))
+            .collect()
+    }
+}
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct UnitRegistry {
+    stats: HashMap<UnitType, UnitStats>,
+}
+
+impl UnitRegistry {
+    pub fn get(&self, unit_type: &UnitType) -> &UnitStats {
+        // Every variant is checked when the registry is built
+        &self.stats[unit_type]
+    }
+
+    // Makes soldiers, special forces and vehicles tougher or weaker for a difficulty preset
+    pub fn scale_military(&mut self, health: f32, damage: f32) {
+        for (unit_type, stats) in self.stats.iter_mut() {
+            if is_military_unit(unit_type) {
+                stats.health *= health;
+                stats.damage *= damage;
+            }
+        }
+    }
+}
+
+impl UnitRegistry {
+    // A missing entry would leave units without stats, and a negative or NaN
+    // cooldown would panic when it becomes a Duration
+    fn problems(&self) -> Vec<String> {
+        let missing: Vec<&str> = UnitType::NAMES
+            .iter()
+            .filter(|name| {
+                let unit_type = name.parse::<UnitType>().expect("UnitType::NAMES must parse");
+                !self.stats.contains_key(&unit_type)
+            })
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            return vec![format!("no entry for: {}", missing.join(", "))];
+        }
+        UnitType::NAMES
+            .iter()
+            .flat_map(|name| {
+                let unit_type = name.parse::<UnitType>().expect("UnitType::NAMES must parse");
+                self.stats[&unit_type].problems(name)
+            })
+            .collect()
+    }
+}
+
+pub fn load_unit_registry_or_builtin(path: &str) -> UnitRegistry {
+    data::load_json_or_builtin(path, BUILTIN_UNIT_STATS, UnitRegistry::problems)
+}
+
+// ==================== HOT RELOAD ====================
+
+#[derive(Default)]
+pub struct UnitStatsWatcher {
+    poll_timer: Option<Timer>,
+    // Set after the first poll, so a file that only shows up later still counts as a change
+    armed: bool,
+    last_modified: Option<SystemTime>,
+}
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+// Polls the stat file once a second and pushes new numbers onto live units
+pub fn unit_stats_hot_reload_system(
+    time: Res<Time>,
+    mut watcher: Local<UnitStatsWatcher>,
+    mut registry: ResMut<UnitRegistry>,
+    preset: Option<Res<DifficultyPreset>>,
+    mut unit_query: Query<(&mut Unit, &mut Sprite, Option<&mut Movement>)>,
+) {
+    let timer = watcher
+        .poll_timer
+        .get_or_insert_with(|| Timer::new(Duration::from_secs(1), TimerMode::Repeating));
+    timer.tick(time.delta());
+    if !timer.just_finished() {
+        return;
+    }
+
+    let modified = modified_time(UNIT_STATS_PATH);
+    if !watcher.armed {
+        watcher.armed = true;
+        watcher.last_modified = modified;
+        return;
+    }
+    if modified == watcher.last_modified {
+        return;
+    }
+    watcher.last_modified = modified;
+
+    let new_registry = match data::load_json(UNIT_STATS_PATH, UnitRegistry::problems) {
+        Ok(new_registry) => new_registry,
+        Err(err) => {
+            error!("❌ Hot reload failed, keeping previous unit stats: {}", err);
+            return;
+        }
+    };
+    *registry = new_registry;
+    if let Some(preset) = preset {
+        registry.scale_military(preset.military_health, preset.military_damage);
+    }
+
+    for (mut unit, mut sprite, movement) in unit_query.iter_mut() {
+        let stats = registry.get(&unit.unit_type);
+        let health_fraction = (unit.health / unit.max_health).clamp(0.0, 1.0);
+
+        unit.max_health = stats.health;
+        unit.health = stats.health * health_fraction;
+        unit.damage = stats.damage;
+        unit.range = stats.range;
+        unit.movement_speed = stats.speed;
+        unit.attack_cooldown.set_duration(stats.cooldown());
+        sprite.color = stats.color();
+        sprite.custom_size = Some(stats.size());
+        if let Some(mut movement) = movement {
+            movement.speed = stats.speed;
+        }
+    }
+
+    info!("📊 Unit stats reloaded from {}", UNIT_STATS_PATH);
+}
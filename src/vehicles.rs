@@ -0,0 +1,569 @@
+// ==================== VEHICLES ====================
+// Military convoys and cartel narco-tech trucks. Vehicles drive the road
+// network instead of the nav grid, travel in convoy order, turn at a limited
+// rate and fire a turret that has to be laid on the target first. They carry
+// infantry (see `passengers` in unit_stats.json) and let it out when they are
+// stopped by a roadblock, run into the enemy or reach their destination.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+
+use crate::difficulty::{acquire_target, DifficultyPreset};
+use crate::map::MapLayout;
+use crate::nav::{NavGrid, ROADBLOCK_BLOCK_RADIUS};
+use crate::sim::PlayerSide;
+use crate::unit_stats::{UnitRegistry, UnitStats};
+use crate::{iso_rotation, spawn_unit, Facing, Faction, Movement, Unit, UnitType, WorldPosition};
+
+// Radians per second a hull can turn
+const TURN_RATE: f32 = 2.5;
+// Radians per second a turret can traverse
+const TURRET_TURN_RATE: f32 = 3.5;
+// The gun fires once it points this close to the target
+const TURRET_ON_TARGET: f32 = 0.1;
+// Road points closer than this are the same junction
+const JUNCTION_RADIUS: f32 = 2.0;
+// Half the width of a road, for deciding a vehicle is already on it
+const ROAD_HALF_WIDTH: f32 = 12.0;
+// A waypoint this close counts as reached
+const WAYPOINT_RADIUS: f32 = 10.0;
+// The route is planned again once the goal has moved this far
+const REROUTE_DISTANCE: f32 = 40.0;
+// How far ahead a driver looks for roadblocks
+const BLOCK_LOOKAHEAD: f32 = 50.0;
+// Vehicles this close together when they set off form one convoy
+const CONVOY_JOIN_RADIUS: f32 = 120.0;
+// Gap each vehicle keeps to the one ahead of it in the convoy
+const CONVOY_SPACING: f32 = 55.0;
+// How far behind the hull passengers climb out
+const DISMOUNT_DISTANCE: f32 = 25.0;
+
+pub fn is_vehicle(unit_type: &UnitType) -> bool {
+    matches!(unit_type, UnitType::Vehicle | UnitType::NarcoTechTruck)
+}
+
+// Place in a convoy; place 0 leads and the others follow in order
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ConvoySlot {
+    pub convoy: u32,
+    pub place: u32,
+}
+
+// The hull heading lives in Facing, like a roadblock's
+#[derive(Component, Clone, Default, Debug)]
+pub struct Vehicle {
+    // Map heading of the mounted gun, radians
+    pub turret: f32,
+    pub passengers: Vec<UnitType>,
+    pub convoy: Option<ConvoySlot>,
+    // Waypoints still to drive, road junctions and the legs on and off the road
+    pub route: Vec<Vec3>,
+    pub route_goal: Option<Vec3>,
+    // Set every tick by the movement system
+    pub blocked: bool,
+    // Set every tick by the turret system; the gun only fires when true
+    pub on_target: bool,
+}
+
+#[derive(Component)]
+pub struct Turret;
+
+// Gives a freshly spawned vehicle its crew, heading and gun
+pub fn equip(commands: &mut Commands, entity: Entity, stats: &UnitStats) {
+    commands.entity(entity).insert((
+        Vehicle {
+            passengers: stats.passengers.clone(),
+            ..default()
+        },
+        Facing(0.0),
+    )).with_children(|parent| {
+        parent.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.15, 0.15, 0.15),
+                    custom_size: Some(Vec2::new(stats.size[0] * 0.6, 4.0)),
+                    anchor: Anchor::CenterLeft,
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, 0.0, 0.5),
+                ..default()
+            },
+            Turret,
+        ));
+    });
+}
+
+// Signed angle from `from` to `to`, in -PI..PI
+fn angle_between(from: f32, to: f32) -> f32 {
+    (to - from + PI).rem_euclid(TAU) - PI
+}
+
+fn turn_toward(current: f32, desired: f32, max_turn: f32) -> f32 {
+    let difference = angle_between(current, desired);
+    (current + difference.clamp(-max_turn, max_turn)).rem_euclid(TAU)
+}
+
+fn distance_to_segment(point: Vec3, start: Vec3, end: Vec3) -> f32 {
+    let (point, start, end) = (point.truncate(), start.truncate(), end.truncate());
+    let segment = end - start;
+    let t = ((point - start).dot(segment) / segment.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+    point.distance(start + segment * t)
+}
+
+// ==================== ROAD NETWORK ====================
+
+// The map roads as a graph of junctions, for vehicles to plan routes over
+#[derive(Resource, Default)]
+pub struct RoadNetwork {
+    nodes: Vec<Vec3>,
+    edges: Vec<Vec<(usize, f32)>>,
+}
+
+impl RoadNetwork {
+    pub fn from_layout(layout: &MapLayout) -> Self {
+        let mut network = Self::default();
+
+        let mut segments = Vec::new();
+        for road in &layout.roads {
+            let points: Vec<usize> = road.points.iter()
+                .map(|point| network.node_at(layout.to_world(*point)))
+                .collect();
+            segments.extend(points.windows(2).map(|pair| (pair[0], pair[1])));
+        }
+
+        // A road that ends on the middle of another joins it there
+        for (start, end) in segments {
+            let (from, to) = (network.nodes[start], network.nodes[end]);
+            let mut stops: Vec<(f32, usize)> = (0..network.nodes.len())
+                .filter(|&node| node != start && node != end)
+                .filter(|&node| distance_to_segment(network.nodes[node], from, to) <= JUNCTION_RADIUS)
+                .map(|node| (network.nodes[node].distance(from), node))
+                .collect();
+            stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let chain: Vec<usize> = std::iter::once(start)
+                .chain(stops.into_iter().map(|(_, node)| node))
+                .chain(std::iter::once(end))
+                .collect();
+            for pair in chain.windows(2) {
+                network.connect(pair[0], pair[1]);
+            }
+        }
+
+        network
+    }
+
+    fn node_at(&mut self, position: Vec3) -> usize {
+        if let Some(index) = self.nodes.iter().position(|node| node.distance(position) <= JUNCTION_RADIUS) {
+            return index;
+        }
+        self.nodes.push(position);
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    fn connect(&mut self, a: usize, b: usize) {
+        if a == b || self.edges[a].iter().any(|(other, _)| *other == b) {
+            return;
+        }
+        let length = self.nodes[a].distance(self.nodes[b]);
+        self.edges[a].push((b, length));
+        self.edges[b].push((a, length));
+    }
+
+    fn nearest(&self, position: Vec3) -> Option<usize> {
+        (0..self.nodes.len()).min_by(|a, b| {
+            self.nodes[*a].distance(position).total_cmp(&self.nodes[*b].distance(position))
+        })
+    }
+
+    // Shortest chain of junctions between two nodes
+    fn shortest_path(&self, start: usize, goal: usize) -> Vec<usize> {
+        let mut distance = vec![f32::INFINITY; self.nodes.len()];
+        let mut previous = vec![None; self.nodes.len()];
+        let mut done = vec![false; self.nodes.len()];
+        distance[start] = 0.0;
+
+        // A handful of junctions, so a plain scan beats a heap
+        while let Some(current) = (0..self.nodes.len())
+            .filter(|&node| !done[node] && distance[node].is_finite())
+            .min_by(|a, b| distance[*a].total_cmp(&distance[*b]))
+        {
+            if current == goal {
+                break;
+            }
+            done[current] = true;
+            for &(next, length) in &self.edges[current] {
+                if distance[current] + length < distance[next] {
+                    distance[next] = distance[current] + length;
+                    previous[next] = Some(current);
+                }
+            }
+        }
+
+        let mut path = vec![goal];
+        let mut current = goal;
+        while let Some(node) = previous[current] {
+            path.push(node);
+            current = node;
+        }
+        if current != start {
+            return Vec::new();
+        }
+        path.reverse();
+        path
+    }
+
+    // Stops from `from` to `to` along the roads, ending on `to`. Goals closer
+    // than the nearest road are driven to directly.
+    pub fn route(&self, from: Vec3, to: Vec3) -> Vec<Vec3> {
+        let (Some(start), Some(end)) = (self.nearest(from), self.nearest(to)) else {
+            return vec![to];
+        };
+        if from.distance(self.nodes[start]) + to.distance(self.nodes[end]) >= from.distance(to) {
+            return vec![to];
+        }
+
+        let mut stops: Vec<Vec3> = self.shortest_path(start, end).into_iter().map(|node| self.nodes[node]).collect();
+        // Already on the first stretch of road: no need to double back to its start
+        if stops.len() >= 2 && distance_to_segment(from, stops[0], stops[1]) <= ROAD_HALF_WIDTH {
+            stops.remove(0);
+        }
+        // Likewise, leave the road where the goal is instead of driving past it
+        let count = stops.len();
+        if count >= 2 && distance_to_segment(to, stops[count - 2], stops[count - 1]) <= ROAD_HALF_WIDTH {
+            stops.pop();
+        }
+        stops.push(to);
+        stops
+    }
+}
+
+// Road route with the legs on and off the road taken round buildings and rivers
+fn plan_route(roads: &RoadNetwork, grid: &NavGrid, from: Vec3, to: Vec3) -> Vec<Vec3> {
+    let stops = roads.route(from, to);
+    let last = stops.len() - 1;
+    let mut route = Vec::new();
+    let mut position = from;
+    for (index, stop) in stops.into_iter().enumerate() {
+        if index == 0 || index == last {
+            route.extend(grid.find_path(position, stop).unwrap_or_else(|| vec![stop]));
+        } else {
+            route.push(stop);
+        }
+        position = stop;
+    }
+    route
+}
+
+// ==================== CONVOYS ====================
+
+// What the convoy logic needs to know about every vehicle on the map
+struct ConvoyMember {
+    entity: Entity,
+    faction: Faction,
+    position: Vec3,
+    slot: Option<ConvoySlot>,
+    // Not going anywhere: at its destination or holding position
+    parked: bool,
+}
+
+// Puts vehicles without a convoy into one, joining a convoy of their side
+// that is close by or starting a new one
+fn assign_convoys(members: &mut [ConvoyMember]) {
+    for index in 0..members.len() {
+        if members[index].slot.is_some() {
+            continue;
+        }
+        let (faction, position) = (members[index].faction.clone(), members[index].position);
+        let joined = members.iter()
+            .filter(|other| other.faction == faction && other.position.distance(position) <= CONVOY_JOIN_RADIUS)
+            .filter_map(|other| other.slot)
+            .map(|slot| slot.convoy)
+            .min();
+
+        let slot = match joined {
+            Some(convoy) => ConvoySlot {
+                convoy,
+                place: members.iter()
+                    .filter_map(|other| other.slot.filter(|slot| slot.convoy == convoy))
+                    .map(|slot| slot.place + 1)
+                    .max()
+                    .unwrap_or(0),
+            },
+            None => ConvoySlot {
+                convoy: members.iter().filter_map(|other| other.slot.map(|slot| slot.convoy)).max().unwrap_or(0) + 1,
+                place: 0,
+            },
+        };
+        members[index].slot = Some(slot);
+    }
+}
+
+// The nearest vehicle still ahead of `slot` in its convoy
+fn vehicle_ahead(members: &[ConvoyMember], slot: ConvoySlot) -> Option<&ConvoyMember> {
+    members.iter()
+        .filter(|other| other.slot.is_some_and(|other| other.convoy == slot.convoy && other.place < slot.place))
+        .max_by_key(|other| other.slot.map(|other| other.place))
+}
+
+// ==================== SYSTEMS ====================
+
+// What drivers go by: the roads, the grid, who is at the wheel and what they carry
+#[derive(SystemParam)]
+pub struct DrivingContext<'w> {
+    time: Res<'w, Time>,
+    registry: Res<'w, UnitRegistry>,
+    roads: Res<'w, RoadNetwork>,
+    grid: Res<'w, NavGrid>,
+    side: Res<'w, PlayerSide>,
+    preset: Res<'w, DifficultyPreset>,
+}
+
+pub fn vehicle_movement_system(
+    mut commands: Commands,
+    context: DrivingContext,
+    mut vehicle_query: Query<(Entity, &mut Unit, &mut WorldPosition, &mut Movement, &mut Facing, &mut Vehicle)>,
+    other_query: Query<(Entity, &Unit, &WorldPosition), Without<Vehicle>>,
+) {
+    let DrivingContext { time, registry, roads, grid, side, preset } = context;
+    let dt = time.delta_seconds();
+
+    let mut members: Vec<ConvoyMember> = vehicle_query.iter()
+        .map(|(entity, unit, position, _, _, vehicle)| ConvoyMember {
+            entity,
+            faction: unit.faction.clone(),
+            position: position.0,
+            slot: vehicle.convoy,
+            parked: vehicle.route_goal.is_none(),
+        })
+        .collect();
+    assign_convoys(&mut members);
+    let member_index: HashMap<Entity, usize> = members.iter()
+        .enumerate()
+        .map(|(index, member)| (member.entity, index))
+        .collect();
+
+    // Vehicles chase targets like infantry does, so they need everyone's position
+    let positions: HashMap<Entity, Vec3> = members.iter()
+        .map(|member| (member.entity, member.position))
+        .chain(other_query.iter().map(|(entity, _, position)| (entity, position.0)))
+        .collect();
+    let roadblocks: Vec<(Entity, Faction, Vec3)> = other_query.iter()
+        .filter(|(_, unit, _)| unit.unit_type == UnitType::Roadblock)
+        .map(|(entity, unit, position)| (entity, unit.faction.clone(), position.0))
+        .collect();
+
+    for (entity, mut unit, mut position, mut movement, mut facing, mut vehicle) in vehicle_query.iter_mut() {
+        let index = member_index[&entity];
+        let slot = members[index].slot;
+        vehicle.convoy = slot;
+
+        // A move order comes first, so the gun keeps firing on the move;
+        // without one the vehicle closes in on its target
+        let target_position = unit.target.and_then(|target| positions.get(&target).copied());
+        let chase = target_position.filter(|target| position.0.distance(*target) > unit.range * 0.8);
+        // Troops get out to fight as soon as the gun has something in range
+        if target_position.is_some_and(|target| position.0.distance(target) <= unit.range) {
+            dismount(&mut commands, &registry, &unit, position.0, facing.0, &mut vehicle);
+        }
+        let ordered = movement.target_position.is_some();
+        let Some(goal) = movement.target_position.or(chase) else {
+            vehicle.route.clear();
+            vehicle.route_goal = None;
+            vehicle.blocked = false;
+            continue;
+        };
+
+        if vehicle.route_goal.is_none_or(|planned| planned.distance(goal) > REROUTE_DISTANCE) {
+            vehicle.route = plan_route(&roads, &grid, position.0, goal);
+            vehicle.route_goal = Some(goal);
+        }
+        while vehicle.route.first().is_some_and(|waypoint| position.0.distance(*waypoint) <= WAYPOINT_RADIUS) {
+            vehicle.route.remove(0);
+        }
+        // Keep the spacing to the vehicle ahead in the convoy
+        let forward = Vec2::from_angle(facing.0);
+        let (tailgating, queued) = slot
+            .and_then(|slot| vehicle_ahead(&members, slot))
+            .filter(|ahead| {
+                let offset = (ahead.position - position.0).truncate();
+                offset.length() < CONVOY_SPACING && offset.dot(forward) > 0.0
+            })
+            .map_or((false, false), |ahead| (true, ahead.parked));
+
+        let Some(&waypoint) = vehicle.route.first().filter(|_| !queued) else {
+            // End of the road, or pulled up behind a convoy that stopped there:
+            // a move order is done and the troops get out
+            vehicle.route_goal = None;
+            vehicle.blocked = false;
+            if ordered {
+                movement.target_position = None;
+                dismount(&mut commands, &registry, &unit, position.0, facing.0, &mut vehicle);
+            }
+            continue;
+        };
+
+        let to_waypoint = (waypoint - position.0).truncate();
+        let direction = to_waypoint.normalize_or_zero();
+
+        // Enemy roadblocks stop the vehicle; a side's own roadblocks leave a gap for its vehicles
+        let blocking = roadblocks.iter()
+            .filter(|(_, faction, _)| *faction != unit.faction)
+            .filter_map(|(roadblock, _, roadblock_position)| {
+                let offset = (*roadblock_position - position.0).truncate();
+                let along = offset.dot(direction);
+                let across = offset.perp_dot(direction).abs();
+                (along > 0.0 && along <= BLOCK_LOOKAHEAD && across <= ROADBLOCK_BLOCK_RADIUS).then_some((*roadblock, along))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(roadblock, _)| roadblock);
+        if blocking.is_some() && !vehicle.blocked {
+            info!("🚧 {:?} {:?} stopped by a roadblock!", unit.faction, unit.unit_type);
+        }
+        vehicle.blocked = blocking.is_some();
+        if let (Some(roadblock), None) = (blocking, unit.target) {
+            if side.is_ai(&unit.faction) {
+                acquire_target(&mut unit, roadblock, preset.ai_reaction);
+            } else {
+                unit.target = Some(roadblock);
+            }
+        }
+
+        // Troops get out to clear the way
+        if vehicle.blocked {
+            dismount(&mut commands, &registry, &unit, position.0, facing.0, &mut vehicle);
+        }
+
+        // Turn toward the waypoint, slowing down for sharp turns and
+        // turning on the spot when it lies behind
+        let desired = direction.y.atan2(direction.x);
+        facing.0 = turn_toward(facing.0, desired, TURN_RATE * dt);
+        if vehicle.blocked || tailgating {
+            continue;
+        }
+        let alignment = angle_between(facing.0, desired).cos().max(0.0);
+        let step = (movement.speed * alignment * dt).min(to_waypoint.length());
+        position.0 += Vec2::from_angle(facing.0).extend(0.0) * step;
+        members[index].position = position.0;
+    }
+}
+
+// Lets the passengers out behind the vehicle
+fn dismount(commands: &mut Commands, registry: &UnitRegistry, unit: &Unit, position: Vec3, heading: f32, vehicle: &mut Vehicle) {
+    if vehicle.passengers.is_empty() {
+        return;
+    }
+    let back = -Vec2::from_angle(heading);
+    let count = vehicle.passengers.len();
+    for (index, passenger) in vehicle.passengers.drain(..).enumerate() {
+        let spread = (index as f32 - (count - 1) as f32 * 0.5) * 15.0;
+        let exit = position + (back * DISMOUNT_DISTANCE + back.perp() * spread).extend(0.0);
+        spawn_unit(commands, registry, passenger, unit.faction.clone(), exit);
+    }
+    info!("🚪 {:?} {:?} dismounts {} troops", unit.faction, unit.unit_type, count);
+}
+
+// Traverses each turret toward its target, or back over the hull when idle
+pub fn turret_system(
+    time: Res<Time>,
+    mut vehicle_query: Query<(&Unit, &WorldPosition, &Facing, &mut Vehicle)>,
+    target_query: Query<&WorldPosition>,
+) {
+    let max_turn = TURRET_TURN_RATE * time.delta_seconds();
+    for (unit, position, facing, mut vehicle) in vehicle_query.iter_mut() {
+        let aim = unit.target
+            .and_then(|target| target_query.get(target).ok())
+            .map(|target| (target.0 - position.0).truncate())
+            .filter(|offset| *offset != Vec2::ZERO)
+            .map(|offset| offset.y.atan2(offset.x));
+
+        vehicle.turret = turn_toward(vehicle.turret, aim.unwrap_or(facing.0), max_turn);
+        vehicle.on_target = aim.is_some_and(|aim| angle_between(vehicle.turret, aim).abs() <= TURRET_ON_TARGET);
+    }
+}
+
+type TurretSprite = (With<Turret>, Without<Vehicle>, Without<Text>);
+
+// Keeps labels upright on a turning hull and points the turret sprite
+pub fn vehicle_render_system(
+    vehicle_query: Query<(&Transform, &Vehicle, &Children)>,
+    mut label_query: Query<&mut Transform, (With<Text>, Without<Vehicle>)>,
+    mut turret_query: Query<&mut Transform, TurretSprite>,
+) {
+    for (transform, vehicle, children) in vehicle_query.iter() {
+        let upright = transform.rotation.inverse();
+        for &child in children.iter() {
+            if let Ok(mut label) = label_query.get_mut(child) {
+                // Undo the last counter-rotation to get the label's offset back
+                let offset = label.rotation.inverse() * label.translation;
+                label.rotation = upright;
+                label.translation = upright * offset;
+            } else if let Ok(mut turret) = turret_query.get_mut(child) {
+                turret.rotation = upright * iso_rotation(vehicle.turret);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 400x400 map with a T of roads: a bar along the bottom and a stem from the
+    // middle of the top half down onto it, plus a wall just above the stem
+    fn t_junction() -> MapLayout {
+        serde_json::from_str(
+            r#"{
+                "map_info": { "name": "test", "bounds": { "width": 400, "height": 400 } },
+                "zones": [],
+                "strategic_points": [],
+                "obstacles": [{ "position": [200, 75], "size": [120, 30] }],
+                "roads": [
+                    { "points": [[50, 350], [350, 350]] },
+                    { "points": [[200, 120], [200, 350]] }
+                ],
+                "spawn_points": { "cartel": [], "military": [], "ovidio": { "position": [0, 0] } }
+            }"#,
+        )
+        .expect("test layout must parse")
+    }
+
+    fn clear(grid: &NavGrid, from: Vec3, to: Vec3) -> bool {
+        (0..=20).all(|step| !grid.is_blocked(grid.world_to_cell(from.lerp(to, step as f32 / 20.0))))
+    }
+
+    #[test]
+    fn routes_follow_the_roads_through_junctions_and_around_buildings() {
+        let layout = t_junction();
+        let (roads, grid) = (RoadNetwork::from_layout(&layout), NavGrid::from_layout(&layout));
+        // Behind the wall at the top of the stem, to just off the east end of the bar
+        let (from, to) = (layout.to_world([200.0, 30.0]), layout.to_world([350.0, 360.0]));
+
+        let route = plan_route(&roads, &grid, from, to);
+        assert_eq!(route.last(), Some(&to));
+        // The stem only reaches the bar through the junction it ends on
+        assert!(route.contains(&layout.to_world([200.0, 350.0])));
+        // The goal is on the bar, so the route leaves it there instead of at its end
+        assert!(!route.contains(&layout.to_world([350.0, 350.0])));
+
+        let mut position = from;
+        for waypoint in &route {
+            assert!(clear(&grid, position, *waypoint), "leg {:?} -> {:?} crosses the wall", position, waypoint);
+            position = *waypoint;
+        }
+    }
+
+    #[test]
+    fn nearby_goals_are_driven_to_directly() {
+        let layout = t_junction();
+        let (roads, grid) = (RoadNetwork::from_layout(&layout), NavGrid::from_layout(&layout));
+        let (from, to) = (layout.to_world([40.0, 150.0]), layout.to_world([60.0, 190.0]));
+
+        assert_eq!(plan_route(&roads, &grid, from, to), vec![to]);
+    }
+}
@@ -0,0 +1,161 @@
+// ==================== WAVE SCHEDULE ====================
+// Military waves come from a schedule file: when each wave arrives, where it
+// enters the map, which units it brings, what its fireteams go after and the
+// radio call that announces it. Waves past the end of the schedule repeat the
+// last one, grown by `growth` each time up to `max_units`.
+// assets/data/waves/historical.json is the default; `--waves <path>` swaps it.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::data;
+use crate::map::MapLayout;
+use crate::squads::SquadObjective;
+use crate::UnitType;
+
+pub const WAVE_SCHEDULE_PATH: &str = "assets/data/waves/historical.json";
+
+const BUILTIN_WAVE_SCHEDULE: &str = include_str!("../assets/data/waves/historical.json");
+
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct WaveSchedule {
+    pub name: String,
+    pub waves: Vec<WaveDefinition>,
+    // Size multiplier for every wave beyond the last scheduled one
+    #[serde(default = "no_growth")]
+    pub growth: f32,
+    // Growth stops once a wave would bring more units than this
+    #[serde(default)]
+    pub max_units: Option<u32>,
+    // Given with `--waves`, so difficulty presets leave it alone
+    #[serde(skip)]
+    pub custom: bool,
+}
+
+fn no_growth() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WaveDefinition {
+    // Seconds after the previous wave, or after the start for the first
+    pub delay: f32,
+    // Entry point, zone or strategic point ids; fireteams take them in turn
+    pub entry_points: Vec<String>,
+    pub units: Vec<WaveUnits>,
+    // Objective of each fireteam in order; teams past the list pick their own
+    #[serde(default)]
+    pub objectives: Vec<SquadObjective>,
+    #[serde(default)]
+    pub radio: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WaveUnits {
+    pub unit_type: UnitType,
+    pub count: u32,
+}
+
+impl WaveSchedule {
+    // Definition of the 1-based `wave`; past the end of the schedule, the last one
+    pub fn wave(&self, wave: u32) -> &WaveDefinition {
+        let index = (wave.max(1) as usize - 1).min(self.waves.len() - 1);
+        &self.waves[index]
+    }
+
+    // Units of the 1-based `wave` in spawn order, each group `size` times as big
+    pub fn roster(&self, wave: u32, size: f32) -> Vec<UnitType> {
+        let extra = wave.saturating_sub(self.waves.len() as u32);
+        let definition = self.wave(wave);
+        let base: u32 = definition.units.iter().map(|group| group.count).sum();
+        let mut growth = self.growth.powi(extra as i32);
+        if let Some(max_units) = self.max_units {
+            growth = growth.min((max_units as f32 / base as f32).max(1.0));
+        }
+        let scale = growth * size;
+        definition.units.iter()
+            .flat_map(|group| {
+                let count = (group.count as f32 * scale).round() as usize;
+                std::iter::repeat_n(group.unit_type.clone(), count)
+            })
+            .collect()
+    }
+
+    // Entry point ids the map does not know
+    pub fn unknown_entry_points(&self, layout: &MapLayout) -> Vec<String> {
+        let mut unknown: Vec<String> = self.waves.iter()
+            .flat_map(|wave| &wave.entry_points)
+            .filter(|id| layout.entry_point(id).is_none())
+            .cloned()
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+        unknown
+    }
+
+    // Everything wrong with the schedule that parsing cannot catch
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.waves.is_empty() {
+            problems.push("no waves".to_string());
+        }
+        if self.growth <= 0.0 {
+            problems.push(format!("growth must be positive, got {}", self.growth));
+        }
+
+        for (index, wave) in self.waves.iter().enumerate() {
+            let number = index + 1;
+            if wave.delay <= 0.0 {
+                problems.push(format!("wave {} has delay {}, expected more than 0", number, wave.delay));
+            }
+            if wave.entry_points.is_empty() {
+                problems.push(format!("wave {} has no entry points", number));
+            }
+            if wave.units.iter().all(|group| group.count == 0) {
+                problems.push(format!("wave {} brings no units", number));
+            }
+        }
+
+        problems
+    }
+}
+
+// Where the teams of a wave come in, in turn, with the names of those places.
+// Unknown ids are skipped; with none left the wave uses every map entry point.
+pub fn entry_positions<'a>(layout: &'a MapLayout, wave: &WaveDefinition) -> Vec<(Vec3, &'a str)> {
+    let resolved: Vec<(Vec3, &str)> = wave.entry_points.iter()
+        .filter_map(|id| layout.entry_point(id))
+        .collect();
+    if !resolved.is_empty() {
+        return resolved;
+    }
+
+    let fallback: Vec<(Vec3, &str)> = layout.spawn_points.entry_points.iter()
+        .map(|entry| (layout.to_world(entry.position), entry.name.as_str()))
+        .collect();
+    if !fallback.is_empty() {
+        return fallback;
+    }
+    let size = layout.size();
+    vec![(layout.to_world([size.x, size.y * 0.5]), "the map edge")]
+}
+
+// Strategic points may be named by id in the schedule; squads go by name
+pub fn resolve_objective(objective: &SquadObjective, layout: &MapLayout) -> SquadObjective {
+    match objective {
+        SquadObjective::Secure(key) => {
+            let name = layout.strategic_points.iter()
+                .find(|point| &point.id == key)
+                .map_or(key, |point| &point.name);
+            SquadObjective::Secure(name.clone())
+        }
+        other => other.clone(),
+    }
+}
+
+// ==================== LOADING ====================
+
+// The historical schedule is the built-in fallback
+pub fn load_wave_schedule_or_builtin(path: &str) -> WaveSchedule {
+    data::load_json_or_builtin(path, BUILTIN_WAVE_SCHEDULE, WaveSchedule::problems)
+}
@@ -0,0 +1,169 @@
+// ==================== ZONE CONTROL ====================
+// City districts change hands when one side holds them unopposed. Built-up
+// zones (infrastructure_level) take longer to take; strategic_value weighs a
+// zone in the end-of-match evaluation. Cartel reinforcements can only be
+// raised in cartel-held zones.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::map::Zone;
+use crate::{Faction, Unit, UnitType, WorldPosition};
+
+// Seconds to take an empty-infrastructure zone; doubles at infrastructure 1.0
+const ZONE_CAPTURE_TIME: f32 = 20.0;
+
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+pub struct ZoneControl {
+    pub owner: Faction,
+    pub capturing: Option<Faction>,
+    pub progress: f32,
+}
+
+impl ZoneControl {
+    pub fn new(owner: Faction) -> Self {
+        Self {
+            owner,
+            capturing: None,
+            progress: 0.0,
+        }
+    }
+}
+
+impl Zone {
+    pub fn contains(&self, position: Vec3) -> bool {
+        let offset = (position - self.center).truncate().abs();
+        offset.x <= self.size.x * 0.5 && offset.y <= self.size.y * 0.5
+    }
+
+    fn capture_time(&self) -> f32 {
+        ZONE_CAPTURE_TIME * (1.0 + self.infrastructure_level.clamp(0.0, 1.0))
+    }
+}
+
+pub fn zone_tint(owner: &Faction) -> Color {
+    match owner {
+        Faction::Cartel => Color::rgba(0.8, 0.2, 0.2, 0.25),
+        Faction::Military => Color::rgba(0.2, 0.6, 0.2, 0.25),
+        Faction::Civilian => Color::rgba(0.6, 0.6, 0.7, 0.2),
+    }
+}
+
+pub fn zone_control_system(
+    time: Res<Time>,
+    unit_query: Query<(&Unit, &WorldPosition)>,
+    mut zone_query: Query<(&Zone, &mut ZoneControl)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (zone, mut control) in zone_query.iter_mut() {
+        let mut cartel = false;
+        let mut military = false;
+        for (unit, position) in unit_query.iter() {
+            if matches!(unit.unit_type, UnitType::Roadblock | UnitType::Ovidio) || !zone.contains(position.0) {
+                continue;
+            }
+            match unit.faction {
+                Faction::Cartel => cartel = true,
+                Faction::Military => military = true,
+                Faction::Civilian => {}
+            }
+        }
+
+        let present = match (cartel, military) {
+            (true, false) => Some(Faction::Cartel),
+            (false, true) => Some(Faction::Military),
+            _ => None,
+        };
+
+        match present {
+            Some(faction) if faction != control.owner => {
+                if control.capturing.as_ref() != Some(&faction) {
+                    control.capturing = Some(faction);
+                    control.progress = 0.0;
+                }
+                control.progress += dt;
+            }
+            Some(_) => {
+                control.capturing = None;
+                control.progress = 0.0;
+            }
+            None => {
+                control.progress = (control.progress - dt).max(0.0);
+                if control.progress == 0.0 {
+                    control.capturing = None;
+                }
+            }
+        }
+
+        if control.progress >= zone.capture_time() {
+            if let Some(faction) = control.capturing.take() {
+                match faction {
+                    Faction::Cartel => info!("🔴 {} falls under cartel control", zone.name),
+                    _ => info!("🟢 Military takes control of {}", zone.name),
+                }
+                control.owner = faction;
+            }
+            control.progress = 0.0;
+        }
+    }
+}
+
+// Tints every zone by owner; a zone being taken fades toward the attacker
+pub fn zone_tint_system(mut zone_query: Query<(&Zone, &ZoneControl, &mut Sprite)>) {
+    for (zone, control, mut sprite) in zone_query.iter_mut() {
+        let owner = zone_tint(&control.owner);
+        sprite.color = match &control.capturing {
+            Some(attacker) => {
+                let share = (control.progress / zone.capture_time()).clamp(0.0, 1.0);
+                let attacker = zone_tint(attacker);
+                Color::rgba(
+                    owner.r() + (attacker.r() - owner.r()) * share,
+                    owner.g() + (attacker.g() - owner.g()) * share,
+                    owner.b() + (attacker.b() - owner.b()) * share,
+                    owner.a() + (attacker.a() - owner.a()) * share,
+                )
+            }
+            None => owner,
+        };
+    }
+}
+
+// ==================== EVALUATION ====================
+
+// Share of the city's strategic value held by each side
+#[derive(Clone, Copy, Default, Debug, Serialize)]
+pub struct CityControl {
+    pub cartel: f32,
+    pub military: f32,
+}
+
+impl CityControl {
+    pub fn evaluate<'a>(zones: impl Iterator<Item = (&'a Zone, &'a ZoneControl)>) -> Self {
+        let mut total = 0.0;
+        let mut control = CityControl::default();
+        for (zone, zone_control) in zones {
+            total += zone.strategic_value;
+            match zone_control.owner {
+                Faction::Cartel => control.cartel += zone.strategic_value,
+                Faction::Military => control.military += zone.strategic_value,
+                Faction::Civilian => {}
+            }
+        }
+        if total > 0.0 {
+            control.cartel /= total;
+            control.military /= total;
+        }
+        control
+    }
+
+    pub fn verdict(&self) -> &'static str {
+        if self.cartel > 0.5 {
+            "🔴 The cartel owns the streets of Culiacán"
+        } else if self.military > 0.5 {
+            "🟢 Government forces hold most of the city"
+        } else {
+            "⚖️ Control of the city remains divided"
+        }
+    }
+}